@@ -1,6 +1,11 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use simplify_polyline::{simplify, Point};
+#[cfg(feature = "rayon")]
+use simplify_polyline::simplify_par;
+use simplify_polyline::{
+    simplify, simplify_hull_tree, simplify_lang, simplify_opheim, simplify_reumann_witkam,
+    simplify_simd, Point,
+};
 
 pub const BENCH_FIXTURE_1118: &str = include_str!("../fixtures/bench-1118.json");
 pub const BENCH_FIXTURE_73752: &str = include_str!("../fixtures/bench-73752.json");
@@ -69,6 +74,95 @@ fn simplify_lq_73752_pts_tol5(c: &mut Criterion) {
     });
 }
 
+fn simplify_simd_hq_73752_pts(c: &mut Criterion) {
+    let points = fixture_73752();
+    c.bench_function("simplify_simd_hq_73752_pts", |b| {
+        b.iter(|| simplify_simd(&points, 1.0, true))
+    });
+}
+
+fn simplify_simd_lq_73752_pts(c: &mut Criterion) {
+    let points = fixture_73752();
+    c.bench_function("simplify_simd_lq_73752_pts", |b| {
+        b.iter(|| simplify_simd(&points, 1.0, false))
+    });
+}
+
+fn simplify_simd_hq_73752_pts_tol5(c: &mut Criterion) {
+    let points = fixture_73752();
+    c.bench_function("simplify_simd_hq_73752_pts_tol5", |b| {
+        b.iter(|| simplify_simd(&points, 5.0, true))
+    });
+}
+
+fn simplify_simd_lq_73752_pts_tol5(c: &mut Criterion) {
+    let points = fixture_73752();
+    c.bench_function("simplify_simd_lq_73752_pts_tol5", |b| {
+        b.iter(|| simplify_simd(&points, 5.0, false))
+    });
+}
+
+fn simplify_hull_hq_73752_pts(c: &mut Criterion) {
+    let points = fixture_73752();
+    c.bench_function("simplify_hull_hq_73752_pts", |b| {
+        b.iter(|| simplify_hull_tree(&points, 1.0, true))
+    });
+}
+
+fn simplify_hull_hq_zigzag_50000_pts(c: &mut Criterion) {
+    // Every split of a zig-zag of growing amplitude only peels off its last point, which is
+    // Douglas–Peucker's worst case.
+    let points: Vec<Point<2, f64>> = (0..50000)
+        .map(|i| Point {
+            vec: [i as f64, if i % 2 == 0 { 1.0 } else { -1.0 } * i as f64],
+        })
+        .collect();
+    let mut group = c.benchmark_group("zigzag_50000_pts");
+    group.sample_size(10);
+    group.bench_function("simplify_hq", |b| b.iter(|| simplify(&points, 0.01, true)));
+    group.bench_function("simplify_hull_hq", |b| {
+        b.iter(|| simplify_hull_tree(&points, 0.01, true))
+    });
+    group.finish();
+}
+
+fn simplify_reumann_witkam_73752_pts(c: &mut Criterion) {
+    let points = fixture_73752();
+    c.bench_function("simplify_reumann_witkam_73752_pts", |b| {
+        b.iter(|| simplify_reumann_witkam(&points, 1.0))
+    });
+}
+
+fn simplify_opheim_73752_pts(c: &mut Criterion) {
+    let points = fixture_73752();
+    c.bench_function("simplify_opheim_73752_pts", |b| {
+        b.iter(|| simplify_opheim(&points, 1.0, 10.0))
+    });
+}
+
+fn simplify_lang_73752_pts(c: &mut Criterion) {
+    let points = fixture_73752();
+    c.bench_function("simplify_lang_73752_pts", |b| {
+        b.iter(|| simplify_lang(&points, 1.0, 16))
+    });
+}
+
+#[cfg(feature = "rayon")]
+fn simplify_par_hq_73752_pts(c: &mut Criterion) {
+    let points = fixture_73752();
+    c.bench_function("simplify_par_hq_73752_pts", |b| {
+        b.iter(|| simplify_par(&points, 1.0, true))
+    });
+}
+
+#[cfg(feature = "rayon")]
+fn simplify_par_lq_73752_pts(c: &mut Criterion) {
+    let points = fixture_73752();
+    c.bench_function("simplify_par_lq_73752_pts", |b| {
+        b.iter(|| simplify_par(&points, 1.0, false))
+    });
+}
+
 criterion_group!(
     simplify_1118,
     simplify_lq_1118_pts,
@@ -84,4 +178,45 @@ criterion_group!(
     simplify_hq_73752_pts_tol5
 );
 
-criterion_main!(simplify_1118, simplify_73752);
+criterion_group!(
+    simplify_simd_73752,
+    simplify_simd_lq_73752_pts,
+    simplify_simd_lq_73752_pts_tol5,
+    simplify_simd_hq_73752_pts,
+    simplify_simd_hq_73752_pts_tol5
+);
+criterion_group!(
+    simplify_hull_73752,
+    simplify_hull_hq_73752_pts,
+    simplify_hull_hq_zigzag_50000_pts
+);
+criterion_group!(
+    simplify_strip_73752,
+    simplify_reumann_witkam_73752_pts,
+    simplify_opheim_73752_pts,
+    simplify_lang_73752_pts
+);
+#[cfg(feature = "rayon")]
+criterion_group!(
+    simplify_par_73752,
+    simplify_par_lq_73752_pts,
+    simplify_par_hq_73752_pts
+);
+
+#[cfg(not(feature = "rayon"))]
+criterion_main!(
+    simplify_1118,
+    simplify_73752,
+    simplify_simd_73752,
+    simplify_strip_73752,
+    simplify_hull_73752
+);
+#[cfg(feature = "rayon")]
+criterion_main!(
+    simplify_1118,
+    simplify_73752,
+    simplify_simd_73752,
+    simplify_strip_73752,
+    simplify_hull_73752,
+    simplify_par_73752
+);
@@ -0,0 +1,72 @@
+//! Python bindings for [simplify_polyline], exposed as the `simplify_polyline` Python module.
+//!
+//! Points are passed as a `float64` NumPy array of shape `(n, D)`, where `D` is 2, 3 or 4, and
+//! are simplified with exactly the same `f64` arithmetic as the Rust crate.
+
+use std::borrow::Cow;
+
+use numpy::{ndarray::Axis, IntoPyArray, PyArray1, PyArray2, PyReadonlyArray2};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use simplify_polyline::simplify_interleaved;
+
+fn kept_indices(
+    py: Python<'_>,
+    points: &PyReadonlyArray2<'_, f64>,
+    tolerance: f64,
+    high_quality: bool,
+) -> PyResult<Vec<usize>> {
+    let array = points.as_array();
+    let coords = match array.as_slice() {
+        Some(coords) => Cow::Borrowed(coords),
+        None => Cow::Owned(array.iter().copied().collect::<Vec<_>>()),
+    };
+
+    let simplify = match array.ncols() {
+        2 => simplify_interleaved::<2, f64>,
+        3 => simplify_interleaved::<3, f64>,
+        4 => simplify_interleaved::<4, f64>,
+        dim => {
+            return Err(PyValueError::new_err(format!(
+                "expected points of shape (n, 2), (n, 3) or (n, 4), got (n, {dim})"
+            )))
+        }
+    };
+
+    Ok(py.detach(|| simplify(&coords, tolerance, high_quality)))
+}
+
+/// Simplifies a polyline within a given tolerance, returning the kept points as a new array.
+///
+/// Mirrors `simplify(points, tolerance, highQuality)` from simplify-js.
+#[pyfunction]
+#[pyo3(signature = (points, tolerance = 1.0, high_quality = false))]
+fn simplify<'py>(
+    py: Python<'py>,
+    points: PyReadonlyArray2<'py, f64>,
+    tolerance: f64,
+    high_quality: bool,
+) -> PyResult<Bound<'py, PyArray2<f64>>> {
+    let kept = kept_indices(py, &points, tolerance, high_quality)?;
+    Ok(points.as_array().select(Axis(0), &kept).into_pyarray(py))
+}
+
+/// Simplifies a polyline within a given tolerance, returning the row indices of the kept points.
+#[pyfunction]
+#[pyo3(signature = (points, tolerance = 1.0, high_quality = false))]
+fn simplify_indices<'py>(
+    py: Python<'py>,
+    points: PyReadonlyArray2<'py, f64>,
+    tolerance: f64,
+    high_quality: bool,
+) -> PyResult<Bound<'py, PyArray1<usize>>> {
+    let kept = kept_indices(py, &points, tolerance, high_quality)?;
+    Ok(kept.into_pyarray(py))
+}
+
+#[pymodule]
+#[pyo3(name = "simplify_polyline")]
+fn simplify_polyline_python(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(simplify, module)?)?;
+    module.add_function(wrap_pyfunction!(simplify_indices, module)?)?;
+    Ok(())
+}
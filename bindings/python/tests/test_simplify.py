@@ -0,0 +1,37 @@
+import json
+from pathlib import Path
+
+import numpy as np
+import pytest
+
+from simplify_polyline import simplify, simplify_indices
+
+FIXTURES = Path(__file__).parents[3] / "fixtures"
+
+
+def load(name):
+    points = json.loads((FIXTURES / name).read_text())
+    return np.array([[point["x"], point["y"]] for point in points])
+
+
+def test_matches_expected_output():
+    result = simplify(load("test-case.json"), 5.0, False)
+    np.testing.assert_array_equal(result, load("test-case-output.json"))
+
+
+def test_indices_select_simplified_points():
+    points = load("test-case.json")
+    indices = simplify_indices(points, 5.0, True)
+    np.testing.assert_array_equal(points[indices], simplify(points, 5.0, True))
+
+
+def test_non_contiguous_input():
+    points = np.asfortranarray(load("test-case.json"))
+    np.testing.assert_array_equal(
+        simplify(points, 5.0, False), load("test-case-output.json")
+    )
+
+
+def test_rejects_unsupported_dimensions():
+    with pytest.raises(ValueError):
+        simplify(np.zeros((4, 5)))
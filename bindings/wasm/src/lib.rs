@@ -0,0 +1,64 @@
+//! WebAssembly bindings for [simplify_polyline], with the same API as
+//! [simplify-js](https://github.com/mourner/simplify-js).
+
+use js_sys::{Array, Reflect};
+use simplify_polyline::simplify_interleaved;
+use wasm_bindgen::prelude::*;
+
+fn coordinate(point: &JsValue, key: &str) -> Result<f64, JsError> {
+    Reflect::get(point, &JsValue::from_str(key))
+        .ok()
+        .and_then(|value| value.as_f64())
+        .ok_or_else(|| JsError::new(&format!("expected every point to have a numeric `{key}`")))
+}
+
+/// Simplifies an array of `{ x, y }` points within a given tolerance, returning a new array of the
+/// kept point objects.
+///
+/// Like simplify-js, `tolerance` defaults to 1 and `highQuality` defaults to false.
+#[wasm_bindgen]
+pub fn simplify(
+    points: &Array,
+    tolerance: Option<f64>,
+    high_quality: Option<bool>,
+) -> Result<Array, JsError> {
+    let mut coords = Vec::with_capacity(points.length() as usize * 2);
+    for point in points.iter() {
+        coords.push(coordinate(&point, "x")?);
+        coords.push(coordinate(&point, "y")?);
+    }
+
+    let kept = simplify_interleaved::<2, f64>(
+        &coords,
+        tolerance.unwrap_or(1.0),
+        high_quality.unwrap_or(false),
+    );
+
+    Ok(kept.into_iter().map(|i| points.get(i as u32)).collect())
+}
+
+/// Simplifies a `Float64Array` of interleaved coordinates (`x0, y0, x1, y1, ...`) within a given
+/// tolerance, returning the kept coordinates in a new `Float64Array`.
+///
+/// This avoids creating an object per point, and keeps the same points as `simplify`.
+#[wasm_bindgen(js_name = simplifyFlat)]
+pub fn simplify_flat(
+    coords: &[f64],
+    tolerance: Option<f64>,
+    high_quality: Option<bool>,
+) -> Result<Vec<f64>, JsError> {
+    if !coords.len().is_multiple_of(2) {
+        return Err(JsError::new("expected an even number of coordinates"));
+    }
+
+    let kept = simplify_interleaved::<2, f64>(
+        coords,
+        tolerance.unwrap_or(1.0),
+        high_quality.unwrap_or(false),
+    );
+
+    Ok(kept
+        .into_iter()
+        .flat_map(|i| [coords[i * 2], coords[i * 2 + 1]])
+        .collect())
+}
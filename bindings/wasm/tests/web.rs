@@ -0,0 +1,48 @@
+//! Runs with `wasm-pack test --node`, as the bindings need a JavaScript host.
+
+#![cfg(target_arch = "wasm32")]
+
+use js_sys::{Array, Reflect, JSON};
+use simplify_polyline_wasm::{simplify, simplify_flat};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn load(json: &str) -> Array {
+    JSON::parse(json).unwrap().into()
+}
+
+fn flatten(points: &Array) -> Vec<f64> {
+    points
+        .iter()
+        .flat_map(|point| {
+            ["x", "y"].map(|key| {
+                Reflect::get(&point, &JsValue::from_str(key))
+                    .unwrap()
+                    .as_f64()
+                    .unwrap()
+            })
+        })
+        .collect()
+}
+
+#[wasm_bindgen_test]
+fn simplify_matches_fixture() {
+    let points = load(include_str!("../../../fixtures/test-case.json"));
+    let expected = load(include_str!("../../../fixtures/test-case-output.json"));
+
+    let simplified = simplify(&points, Some(5.0), Some(false)).unwrap();
+    assert_eq!(flatten(&simplified), flatten(&expected));
+}
+
+#[wasm_bindgen_test]
+fn simplify_flat_matches_fixture() {
+    let coords = flatten(&load(include_str!("../../../fixtures/test-case.json")));
+    let expected = flatten(&load(include_str!(
+        "../../../fixtures/test-case-output.json"
+    )));
+
+    assert_eq!(
+        simplify_flat(&coords, Some(5.0), Some(false)).unwrap(),
+        expected
+    );
+}
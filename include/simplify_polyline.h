@@ -0,0 +1,84 @@
+#ifndef SIMPLIFY_POLYLINE_H
+#define SIMPLIFY_POLYLINE_H
+
+/* Generated by cbindgen from src/capi.rs, do not edit by hand. */
+
+#include <stdbool.h>
+#include <stddef.h>
+
+/**
+ * The result of a call into the C ABI.
+ */
+typedef enum SimplifyPolylineStatus {
+  /**
+   * Simplification succeeded, and the output buffer has been written to.
+   */
+  SimplifyPolylineStatus_Ok = 0,
+  /**
+   * A required pointer was null.
+   */
+  SimplifyPolylineStatus_NullPointer = 1,
+  /**
+   * `dim` was not 2 or 3.
+   */
+  SimplifyPolylineStatus_InvalidDimension = 2,
+  /**
+   * The output buffer was too small to hold every kept point.
+   */
+  SimplifyPolylineStatus_BufferTooSmall = 3,
+  /**
+   * `num_points * dim` was too large to address.
+   */
+  SimplifyPolylineStatus_TooManyPoints = 4,
+} SimplifyPolylineStatus;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Simplifies a polyline of `num_points` points with `dim` (2 or 3) coordinates each, writing the
+ * indices of the kept points to `out_indices`, and their count to `out_len`.
+ *
+ * # Safety
+ *
+ * - `coords` must point to `num_points * dim` readable doubles.
+ * - `out_indices` must point to `out_capacity` writable `size_t`s.
+ * - `out_len` must point to a writable `size_t`.
+ */
+enum SimplifyPolylineStatus simplify_polyline_indices(const double *coords,
+                                                      size_t num_points,
+                                                      size_t dim,
+                                                      double tolerance,
+                                                      bool high_quality,
+                                                      size_t *out_indices,
+                                                      size_t out_capacity,
+                                                      size_t *out_len);
+
+/**
+ * Simplifies a polyline of `num_points` points with `dim` (2 or 3) coordinates each, writing the
+ * coordinates of the kept points to `out_coords`, and their count to `out_len`.
+ *
+ * `out_capacity` is measured in points, not coordinates.
+ *
+ * # Safety
+ *
+ * - `coords` must point to `num_points * dim` readable doubles.
+ * - `out_coords` must point to `out_capacity * dim` writable doubles, and may be the same
+ *   buffer as `coords`.
+ * - `out_len` must point to a writable `size_t`.
+ */
+enum SimplifyPolylineStatus simplify_polyline_coords(const double *coords,
+                                                     size_t num_points,
+                                                     size_t dim,
+                                                     double tolerance,
+                                                     bool high_quality,
+                                                     double *out_coords,
+                                                     size_t out_capacity,
+                                                     size_t *out_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* SIMPLIFY_POLYLINE_H */
@@ -0,0 +1,362 @@
+//! # Apache Arrow support
+//!
+//! Simplifies [GeoArrow](https://geoarrow.org) `LineString` arrays in their native encoding,
+//! without converting each row into a `Vec<Point<D, f64>>` and back. A `LineString` array is a
+//! `List` (or `LargeList`) of coordinates, where the coordinates are stored either:
+//!
+//! - interleaved, as a `FixedSizeList<Float64>` of 2, 3 or 4 values, or
+//! - separated, as a `Struct` of 2, 3 or 4 `Float64` fields (`x`, `y`, ...).
+//!
+//! The returned array uses the same coordinate encoding, fields and row validity as the input,
+//! so any extension type metadata on the field holding the input still applies to the output.
+//! Null rows stay null, and every other row keeps the same points [simplify](crate::simplify)
+//! would.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::sync::Arc;
+//!
+//! use arrow_array::{
+//!     cast::AsArray, types::Float64Type, Array, FixedSizeListArray, Float64Array, ListArray,
+//! };
+//! use arrow_buffer::OffsetBuffer;
+//! use arrow_schema::{DataType, Field};
+//! use simplify_polyline::arrow::simplify_linestrings;
+//!
+//! let coords = Float64Array::from(vec![0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0]);
+//! let coord_field = Arc::new(Field::new("xy", DataType::Float64, false));
+//! let points = FixedSizeListArray::new(coord_field, 2, Arc::new(coords), None);
+//! let point_field = Arc::new(Field::new("vertices", points.data_type().clone(), false));
+//! let linestrings = ListArray::new(
+//!     point_field,
+//!     OffsetBuffer::from_lengths([5]),
+//!     Arc::new(points),
+//!     None,
+//! );
+//!
+//! let simplified = simplify_linestrings(&linestrings, 1.0, true).unwrap();
+//! let vertices = simplified.values().as_fixed_size_list();
+//! let values = vertices.values().as_primitive::<Float64Type>().values();
+//! assert_eq!(&values[..], &[0.0, 0.0, 4.0, 4.0]);
+//! ```
+
+use alloc::{sync::Arc, vec::Vec};
+use core::ops::Range;
+
+use arrow_array::{
+    cast::AsArray, types::Float64Type, Array, ArrayRef, FixedSizeListArray, Float64Array,
+    GenericListArray, OffsetSizeTrait, StructArray,
+};
+use arrow_buffer::OffsetBuffer;
+use arrow_schema::{ArrowError, DataType};
+
+use crate::{simplify_columns, simplify_interleaved};
+
+/// Simplifies every row of a GeoArrow `LineString` array within a given tolerance.
+///
+/// See [simplify](crate::simplify) for the meaning of `tolerance` and `high_quality`, and the
+/// [module documentation](self) for the supported encodings.
+///
+/// # Errors
+///
+/// Returns [ArrowError::InvalidArgumentError] if the coordinates are not in one of the supported
+/// encodings.
+pub fn simplify_linestrings<O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+    tolerance: f64,
+    high_quality: bool,
+) -> Result<GenericListArray<O>, ArrowError> {
+    let list_field = match array.data_type() {
+        DataType::List(field) | DataType::LargeList(field) => field.clone(),
+        data_type => return Err(unsupported(data_type)),
+    };
+
+    let values = array.values();
+    let (offsets, new_values): (_, ArrayRef) = match values.data_type() {
+        DataType::FixedSizeList(field, dim) => {
+            let coords = values.as_fixed_size_list();
+            let (offsets, kept) =
+                simplify_interleaved_coords(array, coords, tolerance, high_quality)?;
+            let values = gather_interleaved(coords, &kept)?;
+            let new_coords = FixedSizeListArray::try_new(field.clone(), *dim, values, None)?;
+            (offsets, Arc::new(new_coords))
+        }
+        DataType::Struct(fields) => {
+            let coords = values.as_struct();
+            let (offsets, kept) =
+                simplify_separated_coords(array, coords, tolerance, high_quality)?;
+            let columns = gather_separated(coords, &kept)?;
+            let new_coords = StructArray::try_new(fields.clone(), columns, None)?;
+            (offsets, Arc::new(new_coords))
+        }
+        data_type => return Err(unsupported(data_type)),
+    };
+
+    GenericListArray::try_new(list_field, offsets, new_values, array.nulls().cloned())
+}
+
+fn unsupported(data_type: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(alloc::format!(
+        "unsupported GeoArrow LineString data type: {data_type}"
+    ))
+}
+
+fn float64_values(array: &dyn Array) -> Result<&[f64], ArrowError> {
+    array
+        .as_primitive_opt::<Float64Type>()
+        .map(|values| &values.values()[..])
+        .ok_or_else(|| unsupported(array.data_type()))
+}
+
+/// Runs `simplify_row` over the coordinate range of every valid row, returning the offsets of
+/// the simplified rows, and the indices of every kept coordinate.
+fn simplify_rows<O, F>(
+    array: &GenericListArray<O>,
+    mut simplify_row: F,
+) -> (OffsetBuffer<O>, Vec<usize>)
+where
+    O: OffsetSizeTrait,
+    F: FnMut(Range<usize>) -> Vec<usize>,
+{
+    let mut offsets = Vec::with_capacity(array.len() + 1);
+    let mut kept = Vec::new();
+    offsets.push(O::usize_as(0));
+
+    for (row, bounds) in array.value_offsets().windows(2).enumerate() {
+        if array.is_valid(row) {
+            let (start, end) = (bounds[0].as_usize(), bounds[1].as_usize());
+            kept.extend(simplify_row(start..end).into_iter().map(|i| start + i));
+        }
+        offsets.push(O::usize_as(kept.len()));
+    }
+
+    (OffsetBuffer::new(offsets.into()), kept)
+}
+
+fn simplify_interleaved_coords<O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+    coords: &FixedSizeListArray,
+    tolerance: f64,
+    high_quality: bool,
+) -> Result<(OffsetBuffer<O>, Vec<usize>), ArrowError> {
+    let dim = coords.value_length() as usize;
+    let values = &float64_values(coords.values())?[coords.value_offset(0) as usize..];
+
+    macro_rules! simplify_dim {
+        ($d:literal) => {
+            simplify_rows(array, |range| {
+                simplify_interleaved::<$d, f64>(
+                    &values[range.start * $d..range.end * $d],
+                    tolerance,
+                    high_quality,
+                )
+            })
+        };
+    }
+
+    match dim {
+        2 => Ok(simplify_dim!(2)),
+        3 => Ok(simplify_dim!(3)),
+        4 => Ok(simplify_dim!(4)),
+        _ => Err(unsupported(coords.data_type())),
+    }
+}
+
+fn simplify_separated_coords<O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+    coords: &StructArray,
+    tolerance: f64,
+    high_quality: bool,
+) -> Result<(OffsetBuffer<O>, Vec<usize>), ArrowError> {
+    let columns = coords
+        .columns()
+        .iter()
+        .map(|column| float64_values(column.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    macro_rules! simplify_dim {
+        ($d:literal) => {
+            simplify_rows(array, |range| {
+                let mut row_columns: [&[f64]; $d] = [&[]; $d];
+                for (row_column, column) in row_columns.iter_mut().zip(&columns) {
+                    *row_column = &column[range.clone()];
+                }
+                simplify_columns(row_columns, tolerance, high_quality)
+            })
+        };
+    }
+
+    match columns.len() {
+        2 => Ok(simplify_dim!(2)),
+        3 => Ok(simplify_dim!(3)),
+        4 => Ok(simplify_dim!(4)),
+        _ => Err(unsupported(coords.data_type())),
+    }
+}
+
+fn gather_interleaved(coords: &FixedSizeListArray, kept: &[usize]) -> Result<ArrayRef, ArrowError> {
+    let dim = coords.value_length() as usize;
+    let values = &float64_values(coords.values())?[coords.value_offset(0) as usize..];
+
+    let mut new_values = Vec::with_capacity(kept.len() * dim);
+    for &i in kept {
+        new_values.extend_from_slice(&values[i * dim..(i + 1) * dim]);
+    }
+
+    Ok(Arc::new(Float64Array::from(new_values)))
+}
+
+fn gather_separated(coords: &StructArray, kept: &[usize]) -> Result<Vec<ArrayRef>, ArrowError> {
+    coords
+        .columns()
+        .iter()
+        .map(|column| {
+            let values = float64_values(column.as_ref())?;
+            let new_values: Vec<f64> = kept.iter().map(|&i| values[i]).collect();
+            Ok(Arc::new(Float64Array::from(new_values)) as ArrayRef)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{sync::Arc, vec, vec::Vec};
+
+    use arrow_array::{
+        cast::AsArray, types::Float64Type, Array, ArrayRef, FixedSizeListArray, Float64Array,
+        LargeListArray, ListArray, StructArray,
+    };
+    use arrow_buffer::{NullBuffer, OffsetBuffer};
+    use arrow_schema::{DataType, Field, Fields};
+
+    use super::simplify_linestrings;
+    use crate::{simplify, Point};
+
+    fn rows() -> Vec<Vec<Point<2, f64>>> {
+        (0..5)
+            .map(|row| {
+                (0..50 + row * 10)
+                    .map(|i| {
+                        let x = i as f64 * 0.5;
+                        Point {
+                            vec: [x, (x * (row + 1) as f64 * 0.3).sin() * 3.0],
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn offsets<O: arrow_array::OffsetSizeTrait>(rows: &[Vec<Point<2, f64>>]) -> OffsetBuffer<O> {
+        OffsetBuffer::from_lengths(rows.iter().map(|row| row.len()))
+    }
+
+    fn interleaved(rows: &[Vec<Point<2, f64>>]) -> ArrayRef {
+        let values: Vec<f64> = rows.iter().flatten().flat_map(|p| p.vec).collect();
+        let field = Arc::new(Field::new("xy", DataType::Float64, false));
+        Arc::new(FixedSizeListArray::new(
+            field,
+            2,
+            Arc::new(Float64Array::from(values)),
+            None,
+        ))
+    }
+
+    fn separated(rows: &[Vec<Point<2, f64>>]) -> ArrayRef {
+        let column = |axis: usize| -> ArrayRef {
+            let values: Vec<f64> = rows.iter().flatten().map(|p| p.vec[axis]).collect();
+            Arc::new(Float64Array::from(values))
+        };
+        let fields = Fields::from(vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("y", DataType::Float64, false),
+        ]);
+        Arc::new(StructArray::new(fields, vec![column(0), column(1)], None))
+    }
+
+    fn list_field(values: &ArrayRef) -> Arc<Field> {
+        Arc::new(Field::new("vertices", values.data_type().clone(), false))
+    }
+
+    fn interleaved_rows(array: &ListArray) -> Vec<Vec<Point<2, f64>>> {
+        array
+            .iter()
+            .map(|row| {
+                let row = row.unwrap();
+                let values = row
+                    .as_fixed_size_list()
+                    .values()
+                    .as_primitive::<Float64Type>();
+                values
+                    .values()
+                    .chunks(2)
+                    .map(|xy| Point {
+                        vec: [xy[0], xy[1]],
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn interleaved_matches_simplify() {
+        let rows = rows();
+        let values = interleaved(&rows);
+        let array = ListArray::new(list_field(&values), offsets(&rows), values, None);
+
+        let simplified = simplify_linestrings(&array, 0.5, false).unwrap();
+        let expected: Vec<_> = rows.iter().map(|row| simplify(row, 0.5, false)).collect();
+        assert_eq!(interleaved_rows(&simplified), expected);
+        assert_eq!(simplified.data_type(), array.data_type());
+    }
+
+    #[test]
+    fn separated_matches_simplify() {
+        let rows = rows();
+        let values = separated(&rows);
+        let array = LargeListArray::new(list_field(&values), offsets(&rows), values, None);
+
+        let simplified = simplify_linestrings(&array, 0.5, true).unwrap();
+        assert_eq!(simplified.data_type(), array.data_type());
+        for (row, expected) in rows.iter().enumerate() {
+            let expected = simplify(expected, 0.5, true);
+            let coords = simplified.value(row);
+            let coords = coords.as_struct();
+            let xs = coords.column(0).as_primitive::<Float64Type>();
+            let ys = coords.column(1).as_primitive::<Float64Type>();
+            let actual: Vec<_> = xs
+                .values()
+                .iter()
+                .zip(ys.values().iter())
+                .map(|(&x, &y)| Point { vec: [x, y] })
+                .collect();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn null_rows_stay_null() {
+        let rows = rows();
+        let values = interleaved(&rows);
+        let nulls = NullBuffer::from(vec![true, false, true, true, false]);
+        let array = ListArray::new(list_field(&values), offsets(&rows), values, Some(nulls));
+
+        let simplified = simplify_linestrings(&array, 0.5, true).unwrap();
+        assert_eq!(simplified.nulls(), array.nulls());
+        assert_eq!(simplified.value_length(1), 0);
+        assert_eq!(simplified.value_length(4), 0);
+    }
+
+    #[test]
+    fn err_unsupported_coordinates() {
+        let values: ArrayRef = Arc::new(Float64Array::from(vec![0.0, 1.0, 2.0]));
+        let array = ListArray::new(
+            list_field(&values),
+            OffsetBuffer::from_lengths([3]),
+            values,
+            None,
+        );
+        assert!(simplify_linestrings(&array, 1.0, true).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use core::{mem, slice};
+
+use crate::{ExtendedNumOps, Point};
+
+/// Views a slice of some other vector type as a slice of [Point]s, without copying it, so that it
+/// can be passed straight to [simplify](crate::simplify) and friends.
+///
+/// This is implemented for slices of `[T; D]`, and with the `glam`, `mint` and `nalgebra`
+/// features, for slices of their 2D and 3D vector and point types.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let coords = [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0], [4.0, 4.0]];
+///
+/// assert_eq!(simplify(coords.as_points(), 1.0, true), points![(0.0, 0.0), (4.0, 4.0)]);
+/// ```
+pub trait AsPoints<const D: usize, T: ExtendedNumOps> {
+    /// Returns the same memory, viewed as points.
+    fn as_points(&self) -> &[Point<D, T>];
+}
+
+/// Reinterprets `values` as points.
+///
+/// # Safety
+///
+/// `U` must be laid out exactly like `[T; D]`, which the size and alignment checks can only
+/// partially confirm.
+#[inline(always)]
+pub(crate) unsafe fn cast_points<U, const D: usize, T: ExtendedNumOps>(
+    values: &[U],
+) -> &[Point<D, T>] {
+    assert!(
+        mem::size_of::<U>() == mem::size_of::<Point<D, T>>()
+            && mem::align_of::<U>() == mem::align_of::<Point<D, T>>()
+    );
+    slice::from_raw_parts(values.as_ptr() as *const Point<D, T>, values.len())
+}
+
+impl<const D: usize, T: ExtendedNumOps> AsPoints<D, T> for [Point<D, T>] {
+    fn as_points(&self) -> &[Point<D, T>] {
+        self
+    }
+}
+
+impl<const D: usize, T: ExtendedNumOps> AsPoints<D, T> for [[T; D]] {
+    fn as_points(&self) -> &[Point<D, T>] {
+        // SAFETY: `Point` is `repr(transparent)` over `[T; D]`.
+        unsafe { cast_points(self) }
+    }
+}
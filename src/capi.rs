@@ -0,0 +1,293 @@
+//! # C ABI
+//!
+//! `extern "C"` functions for simplifying flat buffers of 2D or 3D `double` coordinates
+//! (`x0, y0, x1, y1, ...` or `x0, y0, z0, ...`) from C, C++, Go, or anything else that can call
+//! into a C library. A header for these is kept at `include/simplify_polyline.h`, generated with
+//! [cbindgen](https://github.com/mozilla/cbindgen) from `cbindgen.toml`.
+//!
+//! To build a library to link against:
+//!
+//! ```shell
+//! $ cargo rustc --release --features capi --crate-type cdylib # or staticlib
+//! ```
+//!
+//! Every function writes its results to a caller-provided buffer. If that buffer is too small,
+//! nothing is written to it, [SimplifyPolylineStatus::BufferTooSmall] is returned, and `out_len`
+//! is still set to the number of points that would have been written, so the caller can retry.
+//! A buffer with room for `num_points` points is always large enough.
+
+use core::{mem, slice};
+
+use alloc::vec::Vec;
+
+use crate::simplify_interleaved;
+
+/// The result of a call into the C ABI.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SimplifyPolylineStatus {
+    /// Simplification succeeded, and the output buffer has been written to.
+    Ok = 0,
+    /// A required pointer was null.
+    NullPointer = 1,
+    /// `dim` was not 2 or 3.
+    InvalidDimension = 2,
+    /// The output buffer was too small to hold every kept point.
+    BufferTooSmall = 3,
+    /// `num_points * dim` was too large to address.
+    TooManyPoints = 4,
+}
+
+unsafe fn kept_indices(
+    coords: *const f64,
+    num_points: usize,
+    dim: usize,
+    tolerance: f64,
+    high_quality: bool,
+) -> Result<Vec<usize>, SimplifyPolylineStatus> {
+    let simplify = match dim {
+        2 => simplify_interleaved::<2, f64>,
+        3 => simplify_interleaved::<3, f64>,
+        _ => return Err(SimplifyPolylineStatus::InvalidDimension),
+    };
+    if coords.is_null() && num_points > 0 {
+        return Err(SimplifyPolylineStatus::NullPointer);
+    }
+    let len = num_points
+        .checked_mul(dim)
+        .filter(|&len| len <= isize::MAX as usize / mem::size_of::<f64>())
+        .ok_or(SimplifyPolylineStatus::TooManyPoints)?;
+    let coords = if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(coords, len)
+    };
+
+    Ok(simplify(coords, tolerance, high_quality))
+}
+
+/// Simplifies a polyline of `num_points` points with `dim` (2 or 3) coordinates each, writing the
+/// indices of the kept points to `out_indices`, and their count to `out_len`.
+///
+/// # Safety
+///
+/// - `coords` must point to `num_points * dim` readable doubles.
+/// - `out_indices` must point to `out_capacity` writable `size_t`s.
+/// - `out_len` must point to a writable `size_t`.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn simplify_polyline_indices(
+    coords: *const f64,
+    num_points: usize,
+    dim: usize,
+    tolerance: f64,
+    high_quality: bool,
+    out_indices: *mut usize,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> SimplifyPolylineStatus {
+    if out_indices.is_null() || out_len.is_null() {
+        return SimplifyPolylineStatus::NullPointer;
+    }
+
+    let kept = match kept_indices(coords, num_points, dim, tolerance, high_quality) {
+        Ok(kept) => kept,
+        Err(status) => return status,
+    };
+
+    *out_len = kept.len();
+    if kept.len() > out_capacity {
+        return SimplifyPolylineStatus::BufferTooSmall;
+    }
+    slice::from_raw_parts_mut(out_indices, kept.len()).copy_from_slice(&kept);
+
+    SimplifyPolylineStatus::Ok
+}
+
+/// Simplifies a polyline of `num_points` points with `dim` (2 or 3) coordinates each, writing the
+/// coordinates of the kept points to `out_coords`, and their count to `out_len`.
+///
+/// `out_capacity` is measured in points, not coordinates.
+///
+/// # Safety
+///
+/// - `coords` must point to `num_points * dim` readable doubles.
+/// - `out_coords` must point to `out_capacity * dim` writable doubles, and may be the same
+///   buffer as `coords`.
+/// - `out_len` must point to a writable `size_t`.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn simplify_polyline_coords(
+    coords: *const f64,
+    num_points: usize,
+    dim: usize,
+    tolerance: f64,
+    high_quality: bool,
+    out_coords: *mut f64,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> SimplifyPolylineStatus {
+    if out_coords.is_null() || out_len.is_null() {
+        return SimplifyPolylineStatus::NullPointer;
+    }
+
+    let kept = match kept_indices(coords, num_points, dim, tolerance, high_quality) {
+        Ok(kept) => kept,
+        Err(status) => return status,
+    };
+
+    *out_len = kept.len();
+    if kept.len() > out_capacity {
+        return SimplifyPolylineStatus::BufferTooSmall;
+    }
+    // Kept indices only ever increase, so copying front to back is safe even when writing over
+    // the input buffer.
+    for (out_index, &index) in kept.iter().enumerate() {
+        core::ptr::copy(
+            coords.add(index * dim),
+            out_coords.add(out_index * dim),
+            dim,
+        );
+    }
+
+    SimplifyPolylineStatus::Ok
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ptr;
+
+    use super::*;
+    use crate::{simplify, Point};
+
+    const COORDS: [f64; 16] = [
+        0.0, 0.0, 1.0, 5.0, 2.0, 0.0, 3.0, 5.0, 4.0, 0.0, 5.0, 5.0, 6.0, 0.0, 7.0, 0.5,
+    ];
+
+    fn expected(tolerance: f64) -> Vec<f64> {
+        let points: Vec<Point<2, f64>> = COORDS
+            .chunks(2)
+            .map(|xy| Point {
+                vec: [xy[0], xy[1]],
+            })
+            .collect();
+        simplify(&points, tolerance, true)
+            .iter()
+            .flat_map(|p| p.vec)
+            .collect()
+    }
+
+    #[test]
+    fn coords_match_simplify() {
+        let mut out = [0.0; 16];
+        let mut out_len = 0;
+        let status = unsafe {
+            simplify_polyline_coords(
+                COORDS.as_ptr(),
+                8,
+                2,
+                1.0,
+                true,
+                out.as_mut_ptr(),
+                8,
+                &mut out_len,
+            )
+        };
+        assert_eq!(status, SimplifyPolylineStatus::Ok);
+        assert_eq!(&out[..out_len * 2], expected(1.0).as_slice());
+    }
+
+    #[test]
+    fn coords_in_place() {
+        let mut coords = COORDS;
+        let coords_ptr = coords.as_mut_ptr();
+        let mut out_len = 0;
+        let status = unsafe {
+            simplify_polyline_coords(coords_ptr, 8, 2, 1.0, true, coords_ptr, 8, &mut out_len)
+        };
+        assert_eq!(status, SimplifyPolylineStatus::Ok);
+        assert_eq!(&coords[..out_len * 2], expected(1.0).as_slice());
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let mut out = [0usize; 2];
+        let mut out_len = 0;
+        let status = unsafe {
+            simplify_polyline_indices(
+                COORDS.as_ptr(),
+                8,
+                2,
+                1.0,
+                true,
+                out.as_mut_ptr(),
+                2,
+                &mut out_len,
+            )
+        };
+        assert_eq!(status, SimplifyPolylineStatus::BufferTooSmall);
+        assert_eq!(out_len, expected(1.0).len() / 2);
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        let mut out = [0usize; 8];
+        let mut out_len = 0;
+        let status = unsafe {
+            simplify_polyline_indices(
+                COORDS.as_ptr(),
+                4,
+                4,
+                1.0,
+                true,
+                out.as_mut_ptr(),
+                8,
+                &mut out_len,
+            )
+        };
+        assert_eq!(status, SimplifyPolylineStatus::InvalidDimension);
+
+        let status = unsafe {
+            simplify_polyline_indices(
+                ptr::null(),
+                8,
+                2,
+                1.0,
+                true,
+                out.as_mut_ptr(),
+                8,
+                &mut out_len,
+            )
+        };
+        assert_eq!(status, SimplifyPolylineStatus::NullPointer);
+
+        // The dimension is checked before the length is ever used.
+        let status = unsafe {
+            simplify_polyline_indices(
+                COORDS.as_ptr(),
+                usize::MAX,
+                4,
+                1.0,
+                true,
+                out.as_mut_ptr(),
+                8,
+                &mut out_len,
+            )
+        };
+        assert_eq!(status, SimplifyPolylineStatus::InvalidDimension);
+
+        let status = unsafe {
+            simplify_polyline_indices(
+                COORDS.as_ptr(),
+                usize::MAX,
+                2,
+                1.0,
+                true,
+                out.as_mut_ptr(),
+                8,
+                &mut out_len,
+            )
+        };
+        assert_eq!(status, SimplifyPolylineStatus::TooManyPoints);
+    }
+}
@@ -0,0 +1,301 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Error, ExtendedNumOps, Point};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Integer types that [simplify_checked](crate::simplify_checked) can simplify exactly, by
+/// widening them to [i128].
+///
+/// This is implemented for every primitive integer type up to 64 bits wide, and cannot be
+/// implemented outside of this crate.
+pub trait CheckedInt: ExtendedNumOps + sealed::Sealed {
+    #[doc(hidden)]
+    fn widen(self) -> i128;
+}
+
+macro_rules! impl_checked_int {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl CheckedInt for $t {
+                #[inline(always)]
+                fn widen(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[inline(always)]
+fn checked(value: Option<i128>) -> Result<i128, Error> {
+    value.ok_or(Error::Overflow)
+}
+
+fn widen<const D: usize, T: CheckedInt>(point: &Point<D, T>) -> [i128; D] {
+    let mut wide = [0; D];
+    for (w, &v) in wide.iter_mut().zip(point.vec.iter()) {
+        *w = v.widen();
+    }
+    wide
+}
+
+fn sub<const D: usize>(a: &[i128; D], b: &[i128; D]) -> Result<[i128; D], Error> {
+    let mut difference = [0; D];
+    for i in 0..D {
+        difference[i] = checked(a[i].checked_sub(b[i]))?;
+    }
+    Ok(difference)
+}
+
+fn dot<const D: usize>(a: &[i128; D], b: &[i128; D]) -> Result<i128, Error> {
+    a.iter().zip(b.iter()).try_fold(0i128, |sum, (&a, &b)| {
+        checked(sum.checked_add(checked(a.checked_mul(b))?))
+    })
+}
+
+/// The squared distance from `point` to the segment from `start` to `end`, multiplied by the
+/// squared length of the segment, `sq_len`, so that it is always an integer. Points past either
+/// end, and segments of no length, are measured to the nearest end, and multiplied by `scale`,
+/// which is `sq_len`, or 1 for segments of no length.
+fn scaled_sq_seg_dist<const D: usize>(
+    point: &[i128; D],
+    start: &[i128; D],
+    end: &[i128; D],
+    difference: &[i128; D],
+    sq_len: i128,
+    scale: i128,
+) -> Result<i128, Error> {
+    let offset = sub(point, start)?;
+    let projection = dot(&offset, difference)?;
+
+    if projection <= 0 {
+        checked(dot(&offset, &offset)?.checked_mul(scale))
+    } else if projection >= sq_len {
+        let offset = sub(point, end)?;
+        checked(dot(&offset, &offset)?.checked_mul(scale))
+    } else {
+        // The squared perpendicular distance is (|offset|² * sq_len - projection²) / sq_len, and
+        // by Lagrange's identity, the numerator is the sum of the squared 2D cross products of
+        // every pair of axes, which stays small for points close to the segment.
+        let mut sq_dist = 0i128;
+        for i in 0..D {
+            for j in i + 1..D {
+                let cross = checked(
+                    checked(offset[i].checked_mul(difference[j]))?
+                        .checked_sub(checked(offset[j].checked_mul(difference[i]))?),
+                )?;
+                sq_dist = checked(sq_dist.checked_add(checked(cross.checked_mul(cross))?))?;
+            }
+        }
+        Ok(sq_dist)
+    }
+}
+
+fn simplify_radial_dist_checked<const D: usize>(
+    points: &[[i128; D]],
+    tolerance: i128,
+) -> Result<Vec<usize>, Error> {
+    let mut prev = 0;
+    let mut kept = vec![0];
+
+    for i in 1..points.len() {
+        let difference = sub(&points[i], &points[prev])?;
+        if dot(&difference, &difference)? > tolerance {
+            kept.push(i);
+            prev = i;
+        }
+    }
+
+    if points[prev] != points[points.len() - 1] {
+        kept.push(points.len() - 1);
+    }
+
+    Ok(kept)
+}
+
+fn simplify_dp_step_checked<const D: usize>(
+    points: &[[i128; D]],
+    first: usize,
+    last: usize,
+    tolerance: i128,
+    kept: &mut Vec<usize>,
+) -> Result<(), Error> {
+    let difference = sub(&points[last], &points[first])?;
+    let sq_len = dot(&difference, &difference)?;
+    let scale = sq_len.max(1);
+
+    // Every distance is scaled by the same amount, so they can still be compared directly.
+    let scaled_tolerance = checked(tolerance.checked_mul(scale))?;
+    let mut max_sq_dist = scaled_tolerance;
+    let mut max_index = 0;
+
+    for i in first + 1..last {
+        let sq_dist = scaled_sq_seg_dist(
+            &points[i],
+            &points[first],
+            &points[last],
+            &difference,
+            sq_len,
+            scale,
+        )?;
+        if sq_dist > max_sq_dist {
+            max_index = i;
+            max_sq_dist = sq_dist;
+        }
+    }
+
+    if max_sq_dist > scaled_tolerance {
+        if (max_index - first) > 1 {
+            simplify_dp_step_checked(points, first, max_index, tolerance, kept)?;
+        }
+        kept.push(max_index);
+        if (last - max_index) > 1 {
+            simplify_dp_step_checked(points, max_index, last, tolerance, kept)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Simplifies a polyline with integer coordinates within a given tolerance, exactly, or reports
+/// that it can't.
+///
+/// [simplify](crate::simplify) measures distances exactly in [i128] too, but doesn't check for
+/// overflow, so coordinates too far apart panic in debug builds and wrap in release builds, as
+/// does a tolerance whose square doesn't fit in `T`. This instead checks every operation, the
+/// squared tolerance included. In 2D, it can't overflow as long as every coordinate on each axis
+/// is within `2^31` of every other, and the tolerance is under `2^31`, which covers vector tile
+/// coordinates with room to spare.
+///
+/// # Arguments
+///
+/// - `tolerance`, `high_quality`: see [simplify](crate::simplify).
+///
+/// # Errors
+///
+/// Returns [Error::Overflow] if a distance is too large to represent, even when widened.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let points = points![(0, 0), (5, 1), (10, 0), (20, 10)];
+///
+/// assert_eq!(
+///     simplify_checked(&points, 2, true).unwrap(),
+///     points![(0, 0), (10, 0), (20, 10)]
+/// );
+/// assert_eq!(
+///     simplify_checked(&points![(i64::MIN, 0), (0, 1), (i64::MAX, 0)], 1, true),
+///     Err(Error::Overflow)
+/// );
+/// ```
+pub fn simplify_checked<const D: usize, T: CheckedInt>(
+    points: &[Point<D, T>],
+    tolerance: T,
+    high_quality: bool,
+) -> Result<Vec<Point<D, T>>, Error> {
+    if points.len() <= 2 {
+        return Ok(points.to_vec());
+    }
+
+    let wide: Vec<[i128; D]> = points.iter().map(widen).collect();
+    let tolerance = tolerance.widen();
+    let tolerance_sq = checked(tolerance.checked_mul(tolerance))?;
+
+    let intermediate = if high_quality {
+        (0..wide.len()).collect()
+    } else {
+        simplify_radial_dist_checked(&wide, tolerance_sq)?
+    };
+    let subset: Vec<[i128; D]> = intermediate.iter().map(|&i| wide[i]).collect();
+
+    let mut kept = vec![0];
+    simplify_dp_step_checked(&subset, 0, subset.len() - 1, tolerance_sq, &mut kept)?;
+    kept.push(subset.len() - 1);
+
+    Ok(kept.into_iter().map(|i| points[intermediate[i]]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::simplify_checked;
+    use crate::{simplify, Error, Point};
+
+    fn float(point: &Point<2, i32>) -> Point<2, f64> {
+        Point {
+            vec: [point.vec[0] as f64, point.vec[1] as f64],
+        }
+    }
+
+    fn tile_wave() -> Vec<Point<2, i32>> {
+        (0..2000)
+            .map(|i| Point {
+                vec: [i * 2, ((i * 37) % 101 - 50) * (i % 7) + (i * i) % 13],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_float() {
+        let points = tile_wave();
+        let floats: Vec<Point<2, f64>> = points.iter().map(float).collect();
+
+        for tolerance in [1, 5, 40, 200] {
+            for high_quality in [true, false] {
+                let checked = simplify_checked(&points, tolerance, high_quality).unwrap();
+                let expected = simplify(&floats, tolerance as f64, high_quality);
+                assert_eq!(checked.len(), expected.len());
+                for (c, e) in checked.iter().zip(&expected) {
+                    assert_eq!(float(c), *e);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let points = [
+            Point { vec: [i64::MIN, 0] },
+            Point { vec: [0, 1] },
+            Point { vec: [i64::MAX, 0] },
+        ];
+        assert_eq!(simplify_checked(&points, 1, true), Err(Error::Overflow));
+        assert_eq!(
+            simplify_checked(&points[..2], i64::MAX, true),
+            Ok(points[..2].to_vec())
+        );
+        assert_eq!(
+            simplify_checked(&[Point { vec: [0u64, 0] }; 3], u64::MAX, false),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn large_coordinates() {
+        let points = [
+            Point {
+                vec: [i32::MIN, i32::MIN],
+            },
+            Point { vec: [0, 3] },
+            Point {
+                vec: [i32::MAX, i32::MAX],
+            },
+        ];
+        // (0, 3) is 3 / √2 from the diagonal.
+        assert_eq!(simplify_checked(&points, 2, true), Ok(points.to_vec()));
+        assert_eq!(
+            simplify_checked(&points, 3, true),
+            Ok(vec![points[0], points[2]])
+        );
+    }
+}
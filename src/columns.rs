@@ -0,0 +1,165 @@
+use alloc::vec::Vec;
+
+use crate::{
+    source::{simplify_indices, PointSource},
+    ExtendedNumOps, Point,
+};
+
+struct Columns<'a, const D: usize, T>([&'a [T]; D]);
+
+impl<'a, const D: usize, T: ExtendedNumOps> PointSource<D, T> for Columns<'a, D, T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.first().map_or(0, |column| column.len())
+    }
+
+    #[inline(always)]
+    fn point(&self, index: usize) -> Point<D, T> {
+        let mut vec = [T::zero(); D];
+        for (value, column) in vec.iter_mut().zip(self.0) {
+            *value = column[index];
+        }
+        Point { vec }
+    }
+}
+
+struct Interleaved<'a, const D: usize, T>(&'a [T]);
+
+impl<'a, const D: usize, T: ExtendedNumOps> PointSource<D, T> for Interleaved<'a, D, T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len() / D
+    }
+
+    #[inline(always)]
+    fn point(&self, index: usize) -> Point<D, T> {
+        let mut vec = [T::zero(); D];
+        vec.copy_from_slice(&self.0[index * D..(index + 1) * D]);
+        Point { vec }
+    }
+}
+
+/// Simplifies a polyline stored as one slice per axis, returning the indices of the kept points.
+///
+/// This keeps the same points as [simplify](crate::simplify) would for the equivalent
+/// `&[Point<D, T>]`, without interleaving the coordinates into one first.
+///
+/// # Panics
+///
+/// If the columns are not all the same length.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+/// let ys = [0.0, 1.0, 2.0, 3.0, 4.0];
+///
+/// assert_eq!(simplify_columns([&xs, &ys], 1.0, true), vec![0, 4]);
+/// ```
+pub fn simplify_columns<const D: usize, T: ExtendedNumOps>(
+    columns: [&[T]; D],
+    tolerance: T,
+    high_quality: bool,
+) -> Vec<usize> {
+    let columns = Columns(columns);
+    assert!(
+        columns.0.iter().all(|column| column.len() == columns.len()),
+        "all columns must be the same length"
+    );
+
+    simplify_indices(&columns, tolerance, high_quality)
+}
+
+/// Simplifies a polyline stored as a flat buffer of `D` coordinates per point (`x0, y0, x1, y1,
+/// ...` for 2D), returning the indices of the kept points.
+///
+/// This keeps the same points as [simplify](crate::simplify) would for the equivalent
+/// `&[Point<D, T>]`, without copying the coordinates into one first.
+///
+/// # Panics
+///
+/// If the length of `coords` is not a multiple of `D`.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let coords = [0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0];
+///
+/// assert_eq!(simplify_interleaved::<2, _>(&coords, 1.0, true), vec![0, 4]);
+/// ```
+pub fn simplify_interleaved<const D: usize, T: ExtendedNumOps>(
+    coords: &[T],
+    tolerance: T,
+    high_quality: bool,
+) -> Vec<usize> {
+    assert!(
+        D > 0 && coords.chunks_exact(D).remainder().is_empty(),
+        "coordinate buffer length must be a multiple of the dimension"
+    );
+
+    simplify_indices(&Interleaved::<D, T>(coords), tolerance, high_quality)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{simplify, simplify_columns, simplify_interleaved, Point};
+
+    fn helix(len: usize) -> Vec<Point<3, f64>> {
+        (0..len)
+            .map(|i| {
+                let angle = i as f64 * 0.3;
+                Point {
+                    vec: [angle.cos() * 10.0, angle.sin() * 10.0, i as f64 * 0.05],
+                }
+            })
+            .collect()
+    }
+
+    fn kept_points(points: &[Point<3, f64>], indices: &[usize]) -> Vec<Point<3, f64>> {
+        indices.iter().map(|&i| points[i]).collect()
+    }
+
+    #[test]
+    fn columns_match_simplify() {
+        let points = helix(500);
+        let xs: Vec<f64> = points.iter().map(|p| p.vec[0]).collect();
+        let ys: Vec<f64> = points.iter().map(|p| p.vec[1]).collect();
+        let zs: Vec<f64> = points.iter().map(|p| p.vec[2]).collect();
+
+        for high_quality in [true, false] {
+            for tolerance in [0.5, 2.0] {
+                let indices = simplify_columns([&xs, &ys, &zs], tolerance, high_quality);
+                assert_eq!(
+                    kept_points(&points, &indices),
+                    simplify(&points, tolerance, high_quality)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn interleaved_matches_simplify() {
+        let points = helix(500);
+        let coords: Vec<f64> = points.iter().flat_map(|p| p.vec).collect();
+
+        for high_quality in [true, false] {
+            for tolerance in [0.5, 2.0] {
+                let indices = simplify_interleaved::<3, _>(&coords, tolerance, high_quality);
+                assert_eq!(
+                    kept_points(&points, &indices),
+                    simplify(&points, tolerance, high_quality)
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn columns_of_different_lengths() {
+        simplify_columns([&[0.0, 1.0, 2.0][..], &[0.0, 1.0]], 1.0, true);
+    }
+}
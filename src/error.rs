@@ -0,0 +1,25 @@
+use core::fmt::{self, Display, Formatter};
+
+/// Errors that can be reported by the fallible simplification functions.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The index stack handed to [simplify_in_place](crate::simplify_in_place) was too small to
+    /// hold every pending Douglas–Peucker range.
+    StackExhausted,
+    /// A distance was too large to represent, even after widening, in
+    /// [simplify_checked](crate::simplify_checked).
+    Overflow,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::StackExhausted => write!(f, "index stack exhausted during simplification"),
+            Error::Overflow => write!(f, "arithmetic overflow during simplification"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -0,0 +1,195 @@
+use geo_types::{Coord, CoordNum, LineString, MultiLineString, MultiPolygon, Polygon};
+
+use crate::{source::simplify_indices, Point, Vertex};
+
+impl<T: CoordNum> From<Coord<T>> for Point<2, T> {
+    fn from(coord: Coord<T>) -> Self {
+        Point {
+            vec: [coord.x, coord.y],
+        }
+    }
+}
+
+impl<T: CoordNum> From<Point<2, T>> for Coord<T> {
+    fn from(point: Point<2, T>) -> Self {
+        Coord {
+            x: point.vec[0],
+            y: point.vec[1],
+        }
+    }
+}
+
+impl<T: CoordNum> From<geo_types::Point<T>> for Point<2, T> {
+    fn from(point: geo_types::Point<T>) -> Self {
+        point.0.into()
+    }
+}
+
+impl<T: CoordNum> From<Point<2, T>> for geo_types::Point<T> {
+    fn from(point: Point<2, T>) -> Self {
+        geo_types::Point(point.into())
+    }
+}
+
+impl<T: CoordNum> Vertex<2, T> for Coord<T> {
+    #[inline(always)]
+    fn coords(&self) -> [T; 2] {
+        [self.x, self.y]
+    }
+}
+
+impl<T: CoordNum> Vertex<2, T> for geo_types::Point<T> {
+    #[inline(always)]
+    fn coords(&self) -> [T; 2] {
+        self.0.coords()
+    }
+}
+
+/// Simplification for `geo-types` geometries, without copying their coordinates into a
+/// `Vec<Point<2, T>>` first.
+///
+/// Polygon rings are simplified independently, exactly as if they were line strings. Rings stay
+/// closed, as their first and last coordinates are always kept, but nothing stops a small enough
+/// ring from collapsing below the 4 coordinates a valid ring needs.
+///
+/// Only available with the `geo-types` feature, which also adds conversions between 2D [Point]s
+/// and [geo_types::Coord] / [geo_types::Point].
+///
+/// ## Example
+///
+/// ```rust
+/// use geo_types::{line_string, Coord};
+/// use simplify_polyline::*;
+///
+/// let line = line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0), (x: 2.0, y: 2.0)];
+/// assert_eq!(
+///     line.simplify_polyline(1.0, true),
+///     line_string![(x: 0.0, y: 0.0), (x: 2.0, y: 2.0)],
+/// );
+///
+/// let point: Point<2, f64> = Coord { x: 1.0, y: 2.0 }.into();
+/// assert_eq!(point, point!(1.0, 2.0));
+/// ```
+pub trait SimplifyPolyline<T> {
+    /// Simplifies every line string or ring in this geometry within a given tolerance.
+    ///
+    /// See [simplify](crate::simplify) for the meaning of `tolerance` and `high_quality`.
+    fn simplify_polyline(&self, tolerance: T, high_quality: bool) -> Self;
+}
+
+impl<T: CoordNum> SimplifyPolyline<T> for LineString<T> {
+    fn simplify_polyline(&self, tolerance: T, high_quality: bool) -> Self {
+        simplify_indices(self.0.as_slice(), tolerance, high_quality)
+            .into_iter()
+            .map(|i| self.0[i])
+            .collect()
+    }
+}
+
+impl<T: CoordNum> SimplifyPolyline<T> for MultiLineString<T> {
+    fn simplify_polyline(&self, tolerance: T, high_quality: bool) -> Self {
+        self.iter()
+            .map(|line| line.simplify_polyline(tolerance, high_quality))
+            .collect()
+    }
+}
+
+impl<T: CoordNum> SimplifyPolyline<T> for Polygon<T> {
+    fn simplify_polyline(&self, tolerance: T, high_quality: bool) -> Self {
+        Polygon::new(
+            self.exterior().simplify_polyline(tolerance, high_quality),
+            self.interiors()
+                .iter()
+                .map(|ring| ring.simplify_polyline(tolerance, high_quality))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordNum> SimplifyPolyline<T> for MultiPolygon<T> {
+    fn simplify_polyline(&self, tolerance: T, high_quality: bool) -> Self {
+        self.iter()
+            .map(|polygon| polygon.simplify_polyline(tolerance, high_quality))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use geo_types::{Coord, LineString, MultiLineString, MultiPolygon, Polygon};
+
+    use super::SimplifyPolyline;
+    use crate::{simplify, Point};
+
+    fn zigzag(offset: f64) -> Vec<Point<2, f64>> {
+        (0..20)
+            .map(|i| Point {
+                vec: [i as f64 + offset, ((i % 2) as f64 * 3.0) + (i as f64 * 0.1)],
+            })
+            .collect()
+    }
+
+    fn to_line_string(points: &[Point<2, f64>]) -> LineString<f64> {
+        points.iter().map(|&p| Coord::from(p)).collect()
+    }
+
+    #[test]
+    fn point_round_trip() {
+        let point = Point { vec: [1.5, -2.0] };
+        assert_eq!(Point::from(Coord::from(point)), point);
+        assert_eq!(Point::from(geo_types::Point::from(point)), point);
+    }
+
+    #[test]
+    fn line_string_matches_simplify() {
+        let points = zigzag(0.0);
+        for high_quality in [true, false] {
+            assert_eq!(
+                to_line_string(&points).simplify_polyline(1.0, high_quality),
+                to_line_string(&simplify(&points, 1.0, high_quality))
+            );
+        }
+    }
+
+    #[test]
+    fn multi_line_string_matches_simplify() {
+        let lines = [zigzag(0.0), zigzag(50.0)];
+        let multi: MultiLineString<f64> = lines.iter().map(|l| to_line_string(l)).collect();
+        let expected: MultiLineString<f64> = lines
+            .iter()
+            .map(|l| to_line_string(&simplify(l, 2.0, false)))
+            .collect();
+        assert_eq!(multi.simplify_polyline(2.0, false), expected);
+    }
+
+    #[test]
+    fn polygon_rings_stay_closed() {
+        let mut exterior = zigzag(0.0);
+        exterior.push(Point { vec: [10.0, -10.0] });
+        exterior.push(exterior[0]);
+        let mut interior: Vec<_> = zigzag(0.0)
+            .iter()
+            .map(|p| Point {
+                vec: [p.vec[0] * 0.1 + 2.0, p.vec[1] * 0.1 - 5.0],
+            })
+            .collect();
+        interior.push(interior[0]);
+
+        let polygon = Polygon::new(to_line_string(&exterior), vec![to_line_string(&interior)]);
+        let simplified = MultiPolygon(vec![polygon]).simplify_polyline(1.0, true);
+
+        let polygon = &simplified.0[0];
+        assert_eq!(
+            polygon.exterior(),
+            &to_line_string(&simplify(&exterior, 1.0, true))
+        );
+        assert_eq!(
+            polygon.interiors()[0],
+            to_line_string(&simplify(&interior, 1.0, true))
+        );
+        assert!(polygon.exterior().is_closed());
+        assert!(polygon.interiors()[0].is_closed());
+    }
+}
@@ -0,0 +1,70 @@
+use glam::{DVec2, DVec3, Vec2, Vec3};
+
+use crate::{as_points::cast_points, AsPoints, Point, Vertex};
+
+macro_rules! impl_glam {
+    ($vec:ty, $d:literal, $t:ty) => {
+        impl From<$vec> for Point<$d, $t> {
+            fn from(vec: $vec) -> Self {
+                Point {
+                    vec: vec.to_array(),
+                }
+            }
+        }
+
+        impl From<Point<$d, $t>> for $vec {
+            fn from(point: Point<$d, $t>) -> Self {
+                <$vec>::from_array(point.vec)
+            }
+        }
+
+        impl Vertex<$d, $t> for $vec {
+            #[inline(always)]
+            fn coords(&self) -> [$t; $d] {
+                self.to_array()
+            }
+        }
+
+        impl AsPoints<$d, $t> for [$vec] {
+            fn as_points(&self) -> &[Point<$d, $t>] {
+                // SAFETY: this `glam` type is a `repr(C)` struct of one field per component.
+                unsafe { cast_points(self) }
+            }
+        }
+    };
+}
+
+impl_glam!(Vec2, 2, f32);
+impl_glam!(Vec3, 3, f32);
+impl_glam!(DVec2, 2, f64);
+impl_glam!(DVec3, 3, f64);
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use glam::{DVec2, Vec3};
+
+    use crate::{simplify, AsPoints, Point};
+
+    #[test]
+    fn simplifies_glam_slices() {
+        let vecs: Vec<DVec2> = (0..50)
+            .map(|i| DVec2::new(i as f64, (i as f64 * 0.7).sin() * 4.0))
+            .collect();
+        let points: Vec<Point<2, f64>> = vecs.iter().map(|&v| v.into()).collect();
+
+        assert_eq!(
+            simplify(vecs.as_points(), 1.0, false),
+            simplify(&points, 1.0, false)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let vec = Vec3::new(1.0, 2.0, 3.0);
+        let point: Point<3, f32> = vec.into();
+        assert_eq!(point.vec, [1.0, 2.0, 3.0]);
+        assert_eq!(Vec3::from(point), vec);
+    }
+}
@@ -0,0 +1,796 @@
+use alloc::{vec, vec::Vec};
+use core::{cmp::Ordering, mem};
+
+use num_traits::{float::FloatCore, NumCast};
+
+use crate::{get_sq_seg_dist, max_sq_seg_dist, simplify_radial_dist, ExtendedNumOps, Point};
+
+/// Ranges this short are scanned directly, rather than through their hulls.
+const LEAF_LEN: usize = 64;
+
+/// Searches of ranges this short skip the tree entirely, as a direct scan is faster.
+const SCAN_LEN: usize = 1 << 10;
+
+/// How many times the number of points can be scanned directly before building the tree.
+const SCAN_BUDGET: usize = 64;
+
+/// The point in `first + 1..last` furthest from the segment between `first` and `last`, found by
+/// [max_sq_seg_dist], and its squared distance.
+fn scan<T: ExtendedNumOps>(
+    points: &[Point<2, T>],
+    first: usize,
+    last: usize,
+    tolerance: T,
+) -> (usize, T) {
+    let (max_index, max_sq_dist) = max_sq_seg_dist(points, first, last, tolerance);
+    (max_index, max_sq_dist.value())
+}
+
+#[inline(always)]
+fn perp_dot<T: ExtendedNumOps>(a: &Point<2, T>, b: &Point<2, T>) -> T {
+    a.vec[0] * b.vec[1] - a.vec[1] * b.vec[0]
+}
+
+#[inline(always)]
+fn dot<T: ExtendedNumOps>(a: &Point<2, T>, b: &Point<2, T>) -> T {
+    a.vec[0] * b.vec[0] + a.vec[1] * b.vec[1]
+}
+
+/// A node of a segment tree over the points, holding the ranks of the points in its range sorted
+/// from left to right, until the first search that needs its convex hull replaces them with the
+/// hull's lower and upper chains, each from left to right.
+struct Node {
+    start: usize,
+    end: usize,
+    sorted: Vec<usize>,
+    hull: Option<(Vec<usize>, Vec<usize>)>,
+    children: Option<(usize, usize)>,
+}
+
+/// A search for the point furthest from the segment between `start` and `end`, and the furthest
+/// found so far, which is only replaced by a further point, or an earlier one just as far, so the
+/// result doesn't depend on the order points are considered in.
+struct Search<T: ExtendedNumOps> {
+    start: Point<2, T>,
+    end: Point<2, T>,
+    /// `end - start`.
+    direction: Point<2, T>,
+    /// Directions along which [HullTree::chain_candidates] finds the extremes of the distance
+    /// along the segment, and along both diagonals between it and its normal.
+    axes: [Point<2, T>; 3],
+    sq_len: T,
+    /// How far a bound computed from the hulls may be below a distance [get_sq_seg_dist] rounds
+    /// up to, as a multiple of the squared extent of the points being bounded.
+    ///
+    /// The bound is computed from the products of the segment with each point, rather than the
+    /// way [get_sq_seg_dist] computes a distance, so it can round below the furthest point of a
+    /// node by a few ulps of the squared coordinates, which happens most with points nearly on
+    /// the line through the segment, far from the origin. Pruning a node on that bound could miss
+    /// a point that ties with the furthest so far but comes earlier, so the bound is padded by
+    /// well over the few ulps the rounding can take, which only costs splitting a node that didn't
+    /// strictly need it.
+    rounding: T,
+    tolerance: T,
+    max_index: usize,
+    max_sq_dist: T,
+}
+
+impl<T: ExtendedNumOps + FloatCore> Search<T> {
+    fn new(start: Point<2, T>, end: Point<2, T>, tolerance: T) -> Self {
+        let direction = end - start;
+        Search {
+            start,
+            end,
+            direction,
+            axes: [
+                [direction.vec[0], direction.vec[1]],
+                [
+                    direction.vec[0] - direction.vec[1],
+                    direction.vec[1] + direction.vec[0],
+                ],
+                [
+                    direction.vec[0] + direction.vec[1],
+                    direction.vec[1] - direction.vec[0],
+                ],
+            ]
+            .map(|[x, y]| Point {
+                vec: [y, T::zero() - x],
+            }),
+            sq_len: direction.sq_dist_origin(),
+            rounding: T::epsilon() * <T as NumCast>::from(16).unwrap(),
+            tolerance,
+            max_index: 0,
+            max_sq_dist: tolerance,
+        }
+    }
+
+    fn consider(&mut self, points: &[Point<2, T>], i: usize) {
+        let sq_dist = get_sq_seg_dist(&points[i], &self.start, &self.end);
+        if sq_dist > self.max_sq_dist
+            || (sq_dist == self.max_sq_dist
+                && self.max_sq_dist > self.tolerance
+                && i < self.max_index)
+        {
+            self.max_index = i;
+            self.max_sq_dist = sq_dist;
+        }
+    }
+}
+
+/// A segment tree of convex hulls, which can find the point in any range furthest from a segment
+/// while only looking at a few vertices of the hulls of the parts of the range, as that point is
+/// always a vertex of the range's convex hull.
+struct HullTree<'a, T: ExtendedNumOps + FloatCore> {
+    points: &'a [Point<2, T>],
+    /// The index of every point, sorted from left to right.
+    order: Vec<usize>,
+    nodes: Vec<Node>,
+    /// Reused between searches to collect the hull vertices that need measuring, and those on
+    /// edges parallel to the segment.
+    candidates: Vec<usize>,
+    flats: Vec<usize>,
+}
+
+impl<'a, T: ExtendedNumOps + FloatCore> HullTree<'a, T> {
+    fn new(points: &'a [Point<2, T>]) -> Self {
+        let mut tree = HullTree {
+            points,
+            order: (0..points.len()).collect(),
+            nodes: Vec::new(),
+            candidates: Vec::new(),
+            flats: Vec::new(),
+        };
+        if !points.is_empty() {
+            // Every node needs its points sorted from left to right, so sort them all once, and
+            // merge sort their ranks up the tree. The sort is stable, so identical points stay in
+            // the order they appear in the polyline.
+            let mut order = mem::take(&mut tree.order);
+            order.sort_by(|&a, &b| tree.compare(a, b));
+            let mut ranks = vec![0; points.len()];
+            for (rank, &i) in order.iter().enumerate() {
+                ranks[i] = rank;
+            }
+            tree.order = order;
+            tree.build(0, points.len(), &ranks);
+        }
+        tree
+    }
+
+    /// Builds the node for `start..end`, returning its index.
+    fn build(&mut self, start: usize, end: usize, ranks: &[usize]) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            start,
+            end,
+            sorted: Vec::new(),
+            hull: None,
+            children: None,
+        });
+
+        let sorted = if end - start <= LEAF_LEN {
+            let mut sorted = ranks[start..end].to_vec();
+            sorted.sort_unstable();
+            sorted
+        } else {
+            let middle = start + (end - start) / 2;
+            let left = self.build(start, middle, ranks);
+            let right = self.build(middle, end, ranks);
+            self.nodes[index].children = Some((left, right));
+            merge(&self.nodes[left].sorted, &self.nodes[right].sorted)
+        };
+        self.nodes[index].sorted = sorted;
+
+        index
+    }
+
+    /// Replaces a node's sorted points with the chains of their convex hull, if not done already.
+    fn build_hull(&mut self, node: usize) {
+        if self.nodes[node].hull.is_none() {
+            let sorted = mem::take(&mut self.nodes[node].sorted);
+            let lower = self.chain(sorted.iter().map(|&rank| self.order[rank]));
+            let mut upper = self.chain(sorted.iter().rev().map(|&rank| self.order[rank]));
+            upper.reverse();
+            self.nodes[node].hull = Some((lower, upper));
+        }
+    }
+
+    fn compare(&self, a: usize, b: usize) -> Ordering {
+        let (a, b) = (&self.points[a].vec, &self.points[b].vec);
+        a[0].partial_cmp(&b[0])
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a[1].partial_cmp(&b[1]).unwrap_or(Ordering::Equal))
+    }
+
+    /// One half of Andrew's monotone chain, over already sorted points. Of several identical
+    /// points, only the first in the polyline is kept, as that is the one a scan would pick.
+    fn chain(&self, sorted: impl Iterator<Item = usize>) -> Vec<usize> {
+        let mut chain: Vec<usize> = Vec::new();
+        for i in sorted {
+            if let Some(last) = chain.last_mut() {
+                if self.points[*last] == self.points[i] {
+                    *last = i.min(*last);
+                    continue;
+                }
+            }
+            while chain.len() >= 2
+                && perp_dot(
+                    &(self.points[chain[chain.len() - 1]] - self.points[chain[chain.len() - 2]]),
+                    &(self.points[i] - self.points[chain[chain.len() - 2]]),
+                ) <= T::zero()
+            {
+                chain.pop();
+            }
+            chain.push(i);
+        }
+        chain
+    }
+
+    /// Adds every vertex of `chain` that could be furthest along `direction`'s normal, either way,
+    /// to `candidates`. As the chain is convex, the distance along the normal only turns around
+    /// once, so those are the vertices at either end, and where it turns around, along with the
+    /// far ends of any edges parallel to `direction` there, which are also added to `flats`.
+    fn chain_candidates(
+        &self,
+        chain: &[usize],
+        direction: &Point<2, T>,
+        candidates: &mut Vec<usize>,
+        flats: &mut Vec<usize>,
+    ) {
+        let edge_sign = |k: usize| {
+            let edge = self.points[chain[k + 1]] - self.points[chain[k]];
+            perp_dot(direction, &edge)
+                .partial_cmp(&T::zero())
+                .unwrap_or(Ordering::Equal)
+        };
+        let mut add_flats = |mut k: usize, end: usize, step: fn(usize) -> usize| {
+            candidates.push(chain[k]);
+            while k != end && edge_sign(k.min(step(k))) == Ordering::Equal {
+                flats.push(chain[k]);
+                k = step(k);
+                flats.push(chain[k]);
+                candidates.push(chain[k]);
+            }
+            k
+        };
+        let edges = chain.len() - 1;
+
+        let k = add_flats(0, edges, |k| k + 1);
+        if k == edges {
+            return;
+        }
+
+        let first_sign = edge_sign(k);
+        let (mut low, mut high) = (k + 1, edges);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if edge_sign(middle) == first_sign {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        let low = add_flats(low, edges, |k| k + 1);
+        add_flats(edges, low, |k| k - 1);
+    }
+
+    /// Finds the point in `first + 1..last` furthest from the segment between `first` and
+    /// `last`, exactly as [max_sq_seg_dist] would.
+    fn furthest(&mut self, first: usize, last: usize, tolerance: T) -> (usize, T) {
+        let (start, end) = (self.points[first], self.points[last]);
+        if (end - start).is_origin() {
+            return scan(self.points, first, last, tolerance);
+        }
+
+        let mut search = Search::new(start, end, tolerance);
+        // Measure every part of the range before splitting any, so that the furthest point found
+        // so far rules out as many as possible, and split the most promising first.
+        let mut parts = Vec::new();
+        self.search(0, first + 1, last, &mut search, &mut parts);
+        self.split(parts, &mut search);
+
+        (search.max_index, search.max_sq_dist)
+    }
+
+    /// Measures the parts of the tree that make up `start..end`, adding those that need
+    /// splitting further to `parts`, along with how far their points could be.
+    fn search(
+        &mut self,
+        node: usize,
+        start: usize,
+        end: usize,
+        search: &mut Search<T>,
+        parts: &mut Vec<(usize, T)>,
+    ) {
+        let (node_start, node_end, children) = {
+            let node = &self.nodes[node];
+            (node.start, node.end, node.children)
+        };
+        if start >= node_end || end <= node_start || start >= end {
+            return;
+        }
+
+        match children {
+            Some((left, right)) if start > node_start || end < node_end => {
+                self.search(left, start, end, search, parts);
+                self.search(right, start, end, search, parts);
+            }
+            _ => {
+                if let Some(bound) = self.measure(node, start, end, search) {
+                    parts.push((node, bound));
+                }
+            }
+        }
+    }
+
+    /// Splits each of `parts` that could hold a point further than the furthest found so far,
+    /// measuring both halves, and splitting those further in turn.
+    fn split(&mut self, mut parts: Vec<(usize, T)>, search: &mut Search<T>) {
+        parts.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+        for (node, bound) in parts {
+            if bound < search.max_sq_dist {
+                continue;
+            }
+            let (left, right) = self.nodes[node].children.unwrap();
+            let mut halves = Vec::with_capacity(2);
+            for half in [left, right] {
+                let (start, end) = (self.nodes[half].start, self.nodes[half].end);
+                if let Some(bound) = self.measure(half, start, end, search) {
+                    halves.push((half, bound));
+                }
+            }
+            self.split(halves, search);
+        }
+    }
+
+    /// Measures the points of `node` within `start..end`, which is all of them unless it is a
+    /// leaf, scanning them if it is one, and otherwise through its hull.
+    fn measure(
+        &mut self,
+        node: usize,
+        start: usize,
+        end: usize,
+        search: &mut Search<T>,
+    ) -> Option<T> {
+        let (node_start, node_end, children) = {
+            let node = &self.nodes[node];
+            (node.start, node.end, node.children)
+        };
+        if children.is_some() {
+            return self.measure_hull(node, search);
+        }
+
+        for i in start.max(node_start)..end.min(node_end) {
+            search.consider(self.points, i);
+        }
+        None
+    }
+
+    /// Considers the vertices of a node's hull that are furthest from the line through the
+    /// segment, furthest along it, or furthest along either diagonal between the two, either way.
+    /// If every point of the node lies alongside the segment, its furthest point from the line is
+    /// its furthest from the segment too, and has been considered, so this returns `None`.
+    /// Otherwise, some of its points are measured to the nearest end of the segment instead, and
+    /// this returns a bound on how far any of them can be, so the node only needs searching
+    /// further if that could beat the furthest so far.
+    fn measure_hull(&mut self, node: usize, search: &mut Search<T>) -> Option<T> {
+        self.build_hull(node);
+        let mut candidates = mem::take(&mut self.candidates);
+        let mut flats = mem::take(&mut self.flats);
+        let mut ignored = Vec::new();
+        candidates.clear();
+        flats.clear();
+        {
+            let (lower, upper) = self.nodes[node].hull.as_ref().unwrap();
+            for chain in [lower, upper] {
+                self.chain_candidates(chain, &search.direction, &mut candidates, &mut flats);
+                for axis in &search.axes {
+                    self.chain_candidates(chain, axis, &mut candidates, &mut ignored);
+                }
+            }
+        }
+
+        // The bounds of the node along the segment, across it, and along both diagonals between
+        // the two, each scaled by the length of the segment.
+        let mut along = (T::infinity(), T::neg_infinity());
+        let mut across = (T::infinity(), T::neg_infinity());
+        let mut diagonals = [(T::infinity(), T::neg_infinity()); 2];
+        let extend = |(min, max): (T, T), value: T| (min.min(value), max.max(value));
+        for &i in &candidates {
+            let offset = self.points[i] - search.start;
+            let (u, c) = (
+                dot(&search.direction, &offset),
+                perp_dot(&search.direction, &offset),
+            );
+            along = extend(along, u);
+            across = extend(across, c);
+            diagonals[0] = extend(diagonals[0], u + c);
+            diagonals[1] = extend(diagonals[1], u - c);
+            search.consider(self.points, i);
+        }
+        self.candidates = candidates;
+        let max_across = across.1.max(T::zero() - across.0);
+
+        // Points along an edge parallel to the segment were dropped from the hull, and may be as
+        // far as its ends while coming earlier in the polyline, so the furthest point can only be
+        // trusted if it isn't on one.
+        let on_flat = flats.iter().any(|&i| {
+            perp_dot(&search.direction, &(self.points[i] - search.start)).abs() == max_across
+        });
+        self.flats = flats;
+
+        if along.0 >= T::zero() && along.1 <= search.sq_len && !on_flat {
+            return None;
+        }
+
+        // The node lies within the octagon these bounds make, and the distance to the segment is
+        // convex, so it is no further from the segment than the furthest corner of that.
+        let sq_len = search.sq_len;
+        let sq_dist = |u: T, c: T| {
+            let past_end = (T::zero() - u).max(u - sq_len).max(T::zero());
+            (c * c + past_end * past_end) / sq_len
+        };
+        // Each corner of the box the first two bounds make is either inside the diagonal bound
+        // across it, or cut off by it, leaving the two points where the diagonal meets the box.
+        let corner = |u: T, c: T, inside: bool, cut: [(T, T); 2]| {
+            if inside {
+                sq_dist(u, c)
+            } else {
+                sq_dist(cut[0].0, cut[0].1).max(sq_dist(cut[1].0, cut[1].1))
+            }
+        };
+        let ((u_min, u_max), (c_min, c_max)) = (along, across);
+        let ((s_min, s_max), (t_min, t_max)) = (diagonals[0], diagonals[1]);
+        let bound = [
+            corner(
+                u_max,
+                c_max,
+                u_max + c_max <= s_max,
+                [(u_max, s_max - u_max), (s_max - c_max, c_max)],
+            ),
+            corner(
+                u_min,
+                c_min,
+                u_min + c_min >= s_min,
+                [(u_min, s_min - u_min), (s_min - c_min, c_min)],
+            ),
+            corner(
+                u_max,
+                c_min,
+                u_max - c_min <= t_max,
+                [(u_max, u_max - t_max), (t_max + c_min, c_min)],
+            ),
+            corner(
+                u_min,
+                c_max,
+                u_min - c_max >= t_min,
+                [(u_min, u_min - t_min), (t_min + c_max, c_max)],
+            ),
+        ]
+        .into_iter()
+        .fold(T::zero(), T::max);
+
+        let sq_extent = (max_across * max_across + along.0.abs().max(along.1.abs()).powi(2))
+            / sq_len
+            + search.start.sq_dist_origin()
+            + search.end.sq_dist_origin();
+        Some(bound + sq_extent * search.rounding)
+    }
+}
+
+fn merge(left: &[usize], right: &[usize]) -> Vec<usize> {
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let (mut l, mut r) = (0, 0);
+    while l < left.len() && r < right.len() {
+        if right[r] < left[l] {
+            merged.push(right[r]);
+            r += 1;
+        } else {
+            merged.push(left[l]);
+            l += 1;
+        }
+    }
+    merged.extend_from_slice(&left[l..]);
+    merged.extend_from_slice(&right[r..]);
+    merged
+}
+
+fn simplify_hull_dp<T: ExtendedNumOps + FloatCore>(
+    points: &[Point<2, T>],
+    tolerance: T,
+) -> Vec<Point<2, T>> {
+    // Most polylines split evenly enough that scanning every range directly is fastest, so only
+    // build the tree once that has taken long enough to suggest this one doesn't.
+    let mut tree = None;
+    let mut scan_budget = points.len().saturating_mul(SCAN_BUDGET);
+    let mut kept = vec![0, points.len() - 1];
+    let mut ranges = vec![(0, points.len() - 1)];
+
+    while let Some((first, last)) = ranges.pop() {
+        let len = last - first;
+        if len < 2 {
+            continue;
+        }
+        let (max_index, max_sq_dist) = if len <= SCAN_LEN || len <= scan_budget {
+            scan_budget = scan_budget.saturating_sub(len);
+            scan(points, first, last, tolerance)
+        } else {
+            tree.get_or_insert_with(|| HullTree::new(points))
+                .furthest(first, last, tolerance)
+        };
+        if max_sq_dist > tolerance {
+            kept.push(max_index);
+            ranges.push((first, max_index));
+            ranges.push((max_index, last));
+        }
+    }
+
+    kept.sort_unstable();
+    kept.into_iter().map(|i| points[i]).collect()
+}
+
+/// Simplifies a 2D polyline of `f32` or `f64` within a given tolerance, keeping exactly the same
+/// points as [simplify](crate::simplify), sped up with convex hulls for polylines it would
+/// otherwise struggle with.
+///
+/// Douglas–Peucker spends most of its time finding the point furthest from each segment it
+/// considers, scanning every point in between. That makes it `O(n²)` in the worst case, which is
+/// easy to hit with spirals or zig-zags, where every split only peels off a point or two. The
+/// point furthest from a segment is always a vertex of the convex hull of the points, so this
+/// keeps a segment tree of convex hulls, and any range is covered by the hulls of `O(log n)`
+/// parts of it. This is not Hershberger and Snoeyink's path hull, which measures distances to
+/// the line through each segment rather than the segment itself, and needs a path that doesn't
+/// cross itself.
+///
+/// The vertex of a hull furthest from the line through a segment can be found by binary search.
+/// Where every point of a part lies alongside the segment, that is also the point furthest from
+/// the segment, so a range made up of such parts is searched in `O(log² n)`. Points past either
+/// end of the segment are measured to that end instead, which a hull can only bound, by the
+/// octagon its furthest vertices along the segment, across it, and diagonally make, so parts
+/// reaching past the ends are split further, but only while their bound could beat the furthest
+/// point found so far. For spirals and zig-zags, that only adds a few more hulls to each search,
+/// but a polyline that doubles back past the ends of its segments over and over can still need as
+/// many points measured as [simplify](crate::simplify) would.
+///
+/// Building the tree takes longer than simplifying most polylines, so it is only built once
+/// scanning directly has taken long enough to suggest the polyline is a bad case, and most
+/// polylines are simplified just as quickly as with [simplify](crate::simplify).
+///
+/// # Complexity
+///
+/// Before the tree is built, this scans up to `64n` points directly, as [simplify](crate::simplify)
+/// would. Building the tree takes `O(n log n)` time and memory, and each hull is only computed
+/// the first time a search needs it. A search whose parts all lie alongside the segment then
+/// takes `O(log² n)`, so spirals and zig-zags are simplified in `O(n log² n)` overall, but
+/// searches that keep reaching past the ends of their segments can still take `O(n)` each,
+/// leaving `O(n²)` in the worst case.
+///
+/// See [simplify](crate::simplify) for the meaning of `tolerance` and `high_quality`.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let points = points![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (4.0, 4.0)];
+///
+/// assert_eq!(simplify_hull_tree(&points, 1.0, true), points![(0.0, 0.0), (4.0, 4.0)]);
+/// ```
+pub fn simplify_hull_tree<T: ExtendedNumOps + FloatCore>(
+    points: &[Point<2, T>],
+    tolerance: T,
+    high_quality: bool,
+) -> Vec<Point<2, T>> {
+    if points.len() <= 2 {
+        return points.to_vec();
+    }
+
+    let tolerance_sq = tolerance * tolerance;
+    if high_quality {
+        simplify_hull_dp(points, tolerance_sq)
+    } else {
+        simplify_hull_dp(&simplify_radial_dist(points, tolerance_sq), tolerance_sq)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{scan, simplify_hull_tree, HullTree, Search, SCAN_LEN};
+    use crate::{get_sq_seg_dist, simplify, Point};
+
+    fn spiral(len: usize) -> Vec<Point<2, f64>> {
+        (0..len)
+            .map(|i| {
+                let angle = i as f64 * 0.05;
+                let radius = 1.0 + i as f64 * 0.01;
+                Point {
+                    vec: [angle.cos() * radius, angle.sin() * radius],
+                }
+            })
+            .collect()
+    }
+
+    fn zigzag(len: usize) -> Vec<Point<2, f64>> {
+        (0..len)
+            .map(|i| Point {
+                vec: [i as f64 * 0.37, (i % 2) as f64 * (3.0 + i as f64 * 0.013)],
+            })
+            .collect()
+    }
+
+    /// Every split only peels off the last point, so this is simplified with the tree.
+    fn growing_zigzag(len: usize) -> Vec<Point<2, f64>> {
+        (0..len)
+            .map(|i| Point {
+                vec: [i as f64, if i % 2 == 0 { 1.0 } else { -1.0 } * i as f64],
+            })
+            .collect()
+    }
+
+    fn random_walk(len: usize) -> Vec<Point<2, f64>> {
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+        };
+        let mut point = Point { vec: [0.0, 0.0] };
+        (0..len)
+            .map(|_| {
+                point = point
+                    + Point {
+                        vec: [next(), next()],
+                    };
+                point
+            })
+            .collect()
+    }
+
+    /// A square spiral on a grid, where many points are exactly as far from a segment as each
+    /// other, and many lie past its ends.
+    fn square_spiral(turns: usize) -> Vec<Point<2, f64>> {
+        let mut point = Point { vec: [0.0, 0.0] };
+        let mut points = alloc::vec![point];
+        for turn in 1..=turns {
+            let step = [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.0, -1.0]][turn % 4];
+            for _ in 0..turn {
+                point = point + Point { vec: step };
+                points.push(point);
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn matches_simplify() {
+        for points in [
+            spiral(3000),
+            zigzag(2000),
+            random_walk(5000),
+            growing_zigzag(2000),
+            square_spiral(80),
+        ] {
+            for tolerance in [0.05, 0.5, 1.0, 2.0] {
+                for high_quality in [true, false] {
+                    assert_eq!(
+                        simplify_hull_tree(&points, tolerance, high_quality),
+                        simplify(&points, tolerance, high_quality)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tree_matches_scan() {
+        for points in [random_walk(20000), spiral(20000), square_spiral(200)] {
+            let mut tree = HullTree::new(&points);
+            let len = points.len();
+            for (first, last) in [
+                (0, len - 1),
+                (17, len / 2),
+                (len / 5, len / 5 + SCAN_LEN + 1),
+                (123, len - 124),
+                (len / 2 + 1, len - 1),
+            ] {
+                for tolerance in [0.0, 1.0, 25.0] {
+                    assert_eq!(
+                        tree.furthest(first, last, tolerance),
+                        scan(&points, first, last, tolerance)
+                    );
+                }
+            }
+        }
+    }
+
+    /// Points nearly on a line far from the origin, which go back and forth past both ends of
+    /// any segment between two of them.
+    fn near_collinear(len: usize) -> Vec<Point<2, f64>> {
+        (0..len)
+            .map(|i| {
+                let t = ((i * 7919) % 2001) as f64 - 1000.0;
+                let jitter = ((i * 31) % 3) as f64 - 1.0;
+                Point {
+                    vec: [1.0e6 + t * 0.6, -5.0e5 + t * 0.8 + jitter * 1.0e-10],
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bounds_cover_rounding() {
+        let points = near_collinear(3000);
+        let mut tree = HullTree::new(&points);
+        let mut short = 0;
+        for (first, last) in [(0, 2999), (5, 1500), (700, 2400), (1234, 2345)] {
+            for node in 0..tree.nodes.len() {
+                let (start, end) = (tree.nodes[node].start, tree.nodes[node].end);
+                if tree.nodes[node].children.is_none() {
+                    continue;
+                }
+                let mut search = Search::new(points[first], points[last], 0.0);
+                if let Some(bound) = tree.measure_hull(node, &mut search) {
+                    let furthest = (start..end)
+                        .map(|i| get_sq_seg_dist(&points[i], &search.start, &search.end))
+                        .fold(0.0, f64::max);
+                    assert!(bound >= furthest);
+
+                    search.rounding = 0.0;
+                    if tree.measure_hull(node, &mut search).unwrap() < furthest {
+                        short += 1;
+                    }
+                }
+            }
+        }
+        // Without the margin, some of the bounds fall short of a point they are meant to cover.
+        assert!(short > 0);
+
+        for tolerance in [0.0, 1.0e-9, 1.0] {
+            assert_eq!(
+                tree.furthest(0, 2999, tolerance),
+                scan(&points, 0, 2999, tolerance)
+            );
+            assert_eq!(
+                simplify_hull_tree(&points, tolerance, true),
+                simplify(&points, tolerance, true)
+            );
+        }
+    }
+
+    #[test]
+    fn single_precision() {
+        let points: Vec<Point<2, f32>> = random_walk(3000)
+            .iter()
+            .map(|p| Point {
+                vec: [p.vec[0] as f32, p.vec[1] as f32],
+            })
+            .collect();
+        for tolerance in [0.1, 1.0] {
+            assert_eq!(
+                simplify_hull_tree(&points, tolerance, true),
+                simplify(&points, tolerance, true)
+            );
+        }
+    }
+
+    #[test]
+    fn short_and_degenerate_polylines() {
+        let point = Point { vec: [1.0, 1.0] };
+        for len in 0..=5 {
+            let points = alloc::vec![point; len];
+            assert_eq!(
+                simplify_hull_tree(&points, 1.0, true),
+                simplify(&points, 1.0, true)
+            );
+        }
+
+        // A closed ring, whose first segment has no length.
+        let mut ring = spiral(3000);
+        ring.push(ring[0]);
+        assert_eq!(
+            simplify_hull_tree(&ring, 0.5, true),
+            simplify(&ring, 0.5, true)
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{sq_seg_dist, Error, ExtendedNumOps, Point, SqDist};
+
+fn simplify_radial_dist_in_place<const D: usize, T: ExtendedNumOps>(
+    points: &mut [Point<D, T>],
+    tolerance: T,
+) -> usize {
+    let mut prev_point = points[0];
+    let mut kept = 1;
+    let mut point = prev_point;
+
+    for i in 1..points.len() {
+        point = points[i];
+        if SqDist::between(&point, &prev_point) > SqDist::new(tolerance) {
+            points[kept] = point;
+            kept += 1;
+            prev_point = point;
+        }
+    }
+
+    if prev_point != point {
+        points[kept] = point;
+        kept += 1;
+    }
+
+    kept
+}
+
+// Iterative form of `simplify_dp_step`. The stack only holds the end of each pending range, and
+// ranges are finished left to right, so kept points can be compacted to the front of the slice as
+// soon as their range is done without overwriting anything that is still going to be read.
+fn simplify_douglas_peucker_in_place<const D: usize, T: ExtendedNumOps>(
+    points: &mut [Point<D, T>],
+    tolerance: T,
+    stack: &mut [usize],
+) -> Result<usize, Error> {
+    let mut kept = 1;
+    let mut first = 0;
+    let mut depth = 0;
+
+    if stack.is_empty() {
+        return Err(Error::StackExhausted);
+    }
+    stack[depth] = points.len() - 1;
+    depth += 1;
+
+    while depth > 0 {
+        let last = stack[depth - 1];
+        let mut max_sq_dist = SqDist::new(tolerance);
+        let mut max_index = 0;
+
+        for i in first + 1..last {
+            let sq_dist = sq_seg_dist(&points[i], &points[first], &points[last]);
+            if sq_dist > max_sq_dist {
+                max_index = i;
+                max_sq_dist = sq_dist;
+            }
+        }
+
+        if max_sq_dist > SqDist::new(tolerance) {
+            if depth == stack.len() {
+                return Err(Error::StackExhausted);
+            }
+            stack[depth] = max_index;
+            depth += 1;
+        } else {
+            points[kept] = points[last];
+            kept += 1;
+            first = last;
+            depth -= 1;
+        }
+    }
+
+    Ok(kept)
+}
+
+/// Simplifies a polyline within a given tolerance, without allocating.
+///
+/// This produces the same points as [simplify](crate::simplify), but instead of returning a new
+/// [Vec](alloc::vec::Vec) the kept points are moved to the front of `points`, and their count is
+/// returned. Anything in `points` past that count is left in an unspecified state.
+///
+/// # Arguments
+///
+/// - `tolerance`, `high_quality`: see [simplify](crate::simplify).
+/// - `stack`: scratch space used to track pending Douglas–Peucker ranges. Every index on the
+///   stack is a point that ends up being kept, so a stack as long as the simplified output will
+///   always suffice, and `points.len()` is an upper bound.
+///
+/// # Errors
+///
+/// Returns [Error::StackExhausted] if `stack` is too small. The contents of `points` are
+/// unspecified in that case.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let mut points = points![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (4.0, 4.0)];
+/// let mut stack = [0usize; 8];
+///
+/// let kept = simplify_in_place(&mut points, 1.0, true, &mut stack).unwrap();
+/// assert_eq!(&points[..kept], &points![(0.0, 0.0), (4.0, 4.0)]);
+/// ```
+pub fn simplify_in_place<const D: usize, T: ExtendedNumOps>(
+    points: &mut [Point<D, T>],
+    tolerance: T,
+    high_quality: bool,
+    stack: &mut [usize],
+) -> Result<usize, Error> {
+    if points.len() <= 2 {
+        return Ok(points.len());
+    }
+
+    let tolerance_sq = tolerance * tolerance;
+    let len = if high_quality {
+        points.len()
+    } else {
+        simplify_radial_dist_in_place(points, tolerance_sq)
+    };
+
+    simplify_douglas_peucker_in_place(&mut points[..len], tolerance_sq, stack)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{points, simplify_in_place, Error, Point};
+
+    const ZIGZAG: [Point<2, f64>; 8] = points![
+        (0.0, 0.0),
+        (1.0, 5.0),
+        (2.0, 0.0),
+        (3.0, 5.0),
+        (4.0, 0.0),
+        (5.0, 5.0),
+        (6.0, 0.0),
+        (7.0, 0.5)
+    ];
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn matches_simplify() {
+        for high_quality in [true, false] {
+            for tolerance in [0.1, 1.0, 2.5, 10.0] {
+                let mut points = ZIGZAG;
+                let mut stack = [0usize; ZIGZAG.len()];
+                let kept =
+                    simplify_in_place(&mut points, tolerance, high_quality, &mut stack).unwrap();
+                assert_eq!(
+                    &points[..kept],
+                    crate::simplify(&ZIGZAG, tolerance, high_quality).as_slice()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn err_stack_too_small() {
+        let mut points = ZIGZAG;
+        let mut stack = [0usize; 1];
+        let result = simplify_in_place(&mut points, 0.1, true, &mut stack);
+        assert_eq!(result, Err(Error::StackExhausted));
+    }
+
+    #[test]
+    fn short_input_needs_no_stack() {
+        let mut points = points![(0.0, 0.0), (1.0, 1.0)];
+        assert_eq!(simplify_in_place(&mut points, 1.0, true, &mut []), Ok(2));
+    }
+}
@@ -1,22 +1,264 @@
+#![no_std]
 #![warn(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
 pub use traits::ExtendedNumOps;
 
 /// stub
 #[cfg(feature = "serde")]
 pub mod serde;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "capi")]
+pub mod capi;
+
+#[cfg(feature = "std")]
+pub mod metrics;
+
+mod as_points;
+#[cfg(feature = "alloc")]
+mod checked;
+#[cfg(feature = "alloc")]
+mod columns;
+mod error;
+#[cfg(feature = "geo-types")]
+mod geo_types;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "alloc")]
+mod hull;
+mod in_place;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "alloc")]
+mod optimal;
+#[cfg(feature = "rayon")]
+mod par;
 mod point;
+#[cfg(feature = "alloc")]
+mod report;
+#[cfg(feature = "alloc")]
+mod simd;
+#[cfg(feature = "alloc")]
+mod simplifier;
+#[cfg(feature = "std")]
+mod sleeve;
+#[cfg(feature = "alloc")]
+mod source;
+#[cfg(feature = "alloc")]
+mod strip;
 mod traits;
+#[cfg(feature = "alloc")]
+mod trajectory;
+mod vertex;
+#[cfg(feature = "alloc")]
+mod weighted;
 
+#[cfg(feature = "geo-types")]
+pub use self::geo_types::SimplifyPolyline;
+pub use as_points::AsPoints;
+#[cfg(feature = "alloc")]
+pub use checked::{simplify_checked, CheckedInt};
+#[cfg(feature = "alloc")]
+pub use columns::{simplify_columns, simplify_interleaved};
+pub use error::Error;
+#[cfg(feature = "alloc")]
+pub use hull::simplify_hull_tree;
+pub use in_place::simplify_in_place;
+#[cfg(feature = "alloc")]
+pub use optimal::simplify_optimal;
+#[cfg(feature = "rayon")]
+pub use par::{simplify_many, simplify_par};
 pub use point::Point;
+#[cfg(feature = "alloc")]
+pub use report::{simplify_with_report, SimplifyReport};
+#[cfg(feature = "alloc")]
+pub use simd::{simplify_simd, SimdFloat};
+#[cfg(feature = "alloc")]
+pub use simplifier::{Algorithm, Metric, Simplifier};
+#[cfg(feature = "std")]
+pub use sleeve::simplify_sleeve;
+#[cfg(feature = "alloc")]
+pub use strip::{simplify_lang, simplify_opheim, simplify_reumann_witkam};
+#[cfg(feature = "alloc")]
+pub use trajectory::{simplify_trajectory, simplify_trajectory_opening_window};
+pub use vertex::Vertex;
+#[cfg(feature = "alloc")]
+pub use vertex::{simplify_vertices, simplify_with_data};
+#[cfg(feature = "alloc")]
+pub use weighted::simplify_weighted;
 
-fn get_sq_seg_dist<const D: usize, T: ExtendedNumOps>(
+/// Whether `T` is an integer type, whose division truncates.
+#[inline(always)]
+fn is_integer<T: ExtendedNumOps>() -> bool {
+    T::one() / (T::one() + T::one()) == T::zero()
+}
+
+/// `value` widened to [i128], for measuring distances between points with integer coordinates
+/// without overflowing.
+#[inline(always)]
+fn widen<T: ExtendedNumOps>(value: T) -> i128 {
+    value
+        .to_i128()
+        .expect("integer coordinates must fit in an i128")
+}
+
+/// `point - origin`, widened to [i128].
+#[inline(always)]
+fn wide_offset<const D: usize, T: ExtendedNumOps>(
+    point: &Point<D, T>,
+    origin: &Point<D, T>,
+) -> [i128; D] {
+    let mut offset = [0; D];
+    for (o, (&p, &q)) in offset
+        .iter_mut()
+        .zip(point.vec.iter().zip(origin.vec.iter()))
+    {
+        *o = widen(p) - widen(q);
+    }
+    offset
+}
+
+#[inline(always)]
+fn wide_dot<const D: usize>(a: &[i128; D], b: &[i128; D]) -> i128 {
+    a.iter().zip(b.iter()).map(|(&a, &b)| a * b).sum()
+}
+
+/// A squared distance between points, or from a point to a segment or the line through it.
+///
+/// For floats, this is just the distance. For integer types, it is `whole + fraction /
+/// |end - start|²` for a segment, where `fraction` is less than `|end - start|²`, widened to
+/// [i128], so that it is exact, and can't overflow as long as every coordinate on each axis is
+/// within `2^31` of every other, in 2D.
+///
+/// Distances to the same segment share a denominator, so compare exactly, which is all
+/// Douglas–Peucker needs to pick the furthest point, and comparing one to a whole number compares
+/// it to the distance rounded up. Distances to different segments only compare correctly for
+/// floats, or once [rounded up](SqDist::rounded_up).
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+enum SqDist<T> {
+    Float(T),
+    Int { whole: i128, fraction: i128 },
+}
+
+impl<T: ExtendedNumOps> SqDist<T> {
+    /// A squared distance of exactly `sq_dist`, such as a squared tolerance.
+    #[inline(always)]
+    fn new(sq_dist: T) -> Self {
+        if is_integer::<T>() {
+            SqDist::Int {
+                whole: widen(sq_dist),
+                fraction: 0,
+            }
+        } else {
+            SqDist::Float(sq_dist)
+        }
+    }
+
+    /// The squared distance between two points.
+    #[inline(always)]
+    fn between<const D: usize>(a: &Point<D, T>, b: &Point<D, T>) -> Self {
+        if is_integer::<T>() {
+            let offset = wide_offset(a, b);
+            SqDist::Int {
+                whole: wide_dot(&offset, &offset),
+                fraction: 0,
+            }
+        } else {
+            SqDist::Float(a.sq_dist(b))
+        }
+    }
+
+    /// The distance, rounded up to the next whole number for integer types. As squared tolerances
+    /// are integers too, rounding up never changes whether a point is within one.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn rounded_up(self) -> Self {
+        match self {
+            SqDist::Int { whole, fraction } if fraction > 0 => SqDist::Int {
+                whole: whole + 1,
+                fraction: 0,
+            },
+            _ => self,
+        }
+    }
+
+    /// The distance as a `T`, rounded up to the next whole number for integer types.
+    ///
+    /// # Panics
+    ///
+    /// If the distance doesn't fit in `T`.
+    #[cfg(feature = "alloc")]
+    fn value(self) -> T {
+        match self.rounded_up() {
+            SqDist::Float(sq_dist) => sq_dist,
+            SqDist::Int { whole, .. } => {
+                num_traits::cast(whole).expect("squared distance must fit in the coordinate type")
+            }
+        }
+    }
+}
+
+/// The squared distance from `offset` to the line along `difference` through the origin, for
+/// integer types, where `sq_len` is `|difference|²` and isn't zero. This is the squared cross
+/// product of the two over `sq_len`, which by Lagrange's identity is the sum of the squared 2D
+/// cross products of every pair of axes, so it stays small for points close to the line, with the
+/// division done exactly rather than by rounding the point of projection onto the line.
+fn sq_int_line_dist<const D: usize, T>(
+    offset: &[i128; D],
+    difference: &[i128; D],
+    sq_len: i128,
+) -> SqDist<T> {
+    let mut sq_cross = 0;
+    for i in 0..D {
+        for j in i + 1..D {
+            let cross = offset[i] * difference[j] - offset[j] * difference[i];
+            sq_cross += cross * cross;
+        }
+    }
+
+    SqDist::Int {
+        whole: sq_cross / sq_len,
+        fraction: sq_cross % sq_len,
+    }
+}
+
+/// The squared distance from `pt` to the segment between `start` and `end`, exactly, for
+/// comparing against the distances of other points to the same segment.
+fn sq_seg_dist<const D: usize, T: ExtendedNumOps>(
     pt: &Point<D, T>,
     start: &Point<D, T>,
     end: &Point<D, T>,
-) -> T {
+) -> SqDist<T> {
+    if is_integer::<T>() {
+        let offset = wide_offset(pt, start);
+        let difference = wide_offset(end, start);
+        let sq_len = wide_dot(&difference, &difference);
+        let projection = wide_dot(&offset, &difference);
+        return if projection <= 0 {
+            SqDist::Int {
+                whole: wide_dot(&offset, &offset),
+                fraction: 0,
+            }
+        } else if projection >= sq_len {
+            SqDist::between(pt, end)
+        } else {
+            sq_int_line_dist(&offset, &difference, sq_len)
+        };
+    }
+
     let mut intersection = *start;
     let difference = end - start;
 
@@ -29,9 +271,54 @@ fn get_sq_seg_dist<const D: usize, T: ExtendedNumOps>(
         }
     }
 
-    (pt - intersection).sq_dist_origin()
+    SqDist::Float((pt - intersection).sq_dist_origin())
 }
 
+/// Like [sq_seg_dist], but to the infinite line through `start` and `end`.
+#[cfg(feature = "alloc")]
+fn sq_line_dist<const D: usize, T: ExtendedNumOps>(
+    pt: &Point<D, T>,
+    start: &Point<D, T>,
+    end: &Point<D, T>,
+) -> SqDist<T> {
+    if is_integer::<T>() {
+        let offset = wide_offset(pt, start);
+        let difference = wide_offset(end, start);
+        let sq_len = wide_dot(&difference, &difference);
+        return if sq_len == 0 {
+            SqDist::Int {
+                whole: wide_dot(&offset, &offset),
+                fraction: 0,
+            }
+        } else {
+            sq_int_line_dist(&offset, &difference, sq_len)
+        };
+    }
+
+    let mut intersection = *start;
+    let difference = end - start;
+
+    if !difference.is_origin() {
+        let t = ((pt - start) * difference).value_sum() / difference.sq_dist_origin();
+        intersection = intersection + (difference * t);
+    }
+
+    SqDist::Float((pt - intersection).sq_dist_origin())
+}
+
+/// The squared distance from `pt` to the segment between `start` and `end`, rounded up to a whole
+/// number for integer types.
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn get_sq_seg_dist<const D: usize, T: ExtendedNumOps>(
+    pt: &Point<D, T>,
+    start: &Point<D, T>,
+    end: &Point<D, T>,
+) -> T {
+    sq_seg_dist(pt, start, end).value()
+}
+
+#[cfg(feature = "alloc")]
 fn simplify_radial_dist<const D: usize, T: ExtendedNumOps>(
     points: &[Point<D, T>],
     tolerance: T,
@@ -42,7 +329,7 @@ fn simplify_radial_dist<const D: usize, T: ExtendedNumOps>(
 
     for pt in points.iter().skip(1) {
         point = *pt;
-        if pt.sq_dist(&prev_point) > tolerance {
+        if SqDist::between(pt, &prev_point) > SqDist::new(tolerance) {
             new_points.push(*pt);
             prev_point = *pt;
         }
@@ -55,6 +342,28 @@ fn simplify_radial_dist<const D: usize, T: ExtendedNumOps>(
     new_points
 }
 
+#[cfg(feature = "alloc")]
+fn max_sq_seg_dist<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    first: usize,
+    last: usize,
+    tolerance: T,
+) -> (usize, SqDist<T>) {
+    let mut max_sq_dist = SqDist::new(tolerance);
+    let mut max_index = 0;
+
+    for i in first + 1..last {
+        let sq_dist = sq_seg_dist(&points[i], &points[first], &points[last]);
+        if sq_dist > max_sq_dist {
+            max_index = i;
+            max_sq_dist = sq_dist;
+        }
+    }
+
+    (max_index, max_sq_dist)
+}
+
+#[cfg(feature = "alloc")]
 fn simplify_dp_step<const D: usize, T: ExtendedNumOps>(
     points: &[Point<D, T>],
     first: usize,
@@ -62,18 +371,19 @@ fn simplify_dp_step<const D: usize, T: ExtendedNumOps>(
     tolerance: T,
     simplified: &mut Vec<Point<D, T>>,
 ) {
-    let mut max_sq_dist = tolerance;
+    // The scan is the same as [max_sq_seg_dist], but calling that from here is measurably slower.
+    let mut max_sq_dist = SqDist::new(tolerance);
     let mut max_index = 0;
 
     for i in first + 1..last {
-        let sq_dist = get_sq_seg_dist(&points[i], &points[first], &points[last]);
+        let sq_dist = sq_seg_dist(&points[i], &points[first], &points[last]);
         if sq_dist > max_sq_dist {
             max_index = i;
             max_sq_dist = sq_dist;
         }
     }
 
-    if max_sq_dist > tolerance {
+    if max_sq_dist > SqDist::new(tolerance) {
         if (max_index - first) > 1 {
             simplify_dp_step(points, first, max_index, tolerance, simplified);
         }
@@ -84,6 +394,7 @@ fn simplify_dp_step<const D: usize, T: ExtendedNumOps>(
     }
 }
 
+#[cfg(feature = "alloc")]
 fn simplify_douglas_peucker<const D: usize, T: ExtendedNumOps>(
     points: &[Point<D, T>],
     tolerance: T,
@@ -101,27 +412,131 @@ fn simplify_douglas_peucker<const D: usize, T: ExtendedNumOps>(
 /// # Arguments
 ///
 /// - `tolerance`: A distance measurement used for both radial distance and Douglas–Peucker -- the
-/// higher the tolerance, the more points will be removed from the polyline.
+///   higher the tolerance, the more points will be removed from the polyline.
+///   [Simplifier::radial_tolerance] sets a separate one for radial distance.
 /// - `high_quality`: Controls the algorithm(s) to be used in simplification
 ///   - `true`: this will take the entire array of points and simplify using the Douglas–Peucker
 ///     algorithm.
 ///   - `false`: the list of points are first filtered using a simple radial distance algorithm,
 ///     and then passed to the the Douglas-Peucker algorithm for final simplification.
+///
+/// For integer coordinates, distances are widened to [i128] and measured exactly, as fractions,
+/// rather than by rounding points onto segments, so the same points are kept as exact arithmetic
+/// would keep. In 2D, this can't overflow as long as every coordinate on each axis is within
+/// `2^31` of every other, and the squared tolerance fits in `T`. To also detect overflow, use
+/// [simplify_checked].
+///
+/// For more options, such as other algorithms or limits on the number of points kept, use a
+/// [Simplifier].
+#[cfg(feature = "alloc")]
 pub fn simplify<const D: usize, T: ExtendedNumOps>(
     points: &[Point<D, T>],
     tolerance: T,
     high_quality: bool,
 ) -> Vec<Point<D, T>> {
-    if points.len() <= 2 {
-        return points.to_vec();
+    Simplifier::new(tolerance)
+        .radial_pass(!high_quality)
+        .simplify(points)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use crate::{simplify, simplify_checked, simplify_in_place, Point, Simplifier};
+
+    fn float(point: &Point<2, i32>) -> Point<2, f64> {
+        Point {
+            vec: [point.vec[0] as f64, point.vec[1] as f64],
+        }
     }
 
-    let tolerance_sq = tolerance * tolerance;
-    let intermediate = if high_quality {
-        points.to_vec()
-    } else {
-        simplify_radial_dist(points, tolerance_sq)
-    };
+    #[test]
+    fn simplify_is_exact_for_integers() {
+        // Jagged enough that many points are a fraction over or under a whole distance from the
+        // segments Douglas–Peucker measures them against.
+        let points: Vec<Point<2, i32>> = (0..2000)
+            .map(|i| Point {
+                vec: [i * 2, ((i * 37) % 101 - 50) * (i % 7) + (i * i) % 13],
+            })
+            .collect();
+        let floats: Vec<Point<2, f64>> = points.iter().map(float).collect();
 
-    simplify_douglas_peucker(&intermediate, tolerance_sq)
+        for tolerance in [1, 2, 3, 5, 40, 200] {
+            for high_quality in [true, false] {
+                let simplified = simplify(&points, tolerance, high_quality);
+                let expected = simplify(&floats, tolerance as f64, high_quality);
+                assert_eq!(simplified.iter().map(float).collect::<Vec<_>>(), expected);
+                assert_eq!(
+                    simplified,
+                    simplify_checked(&points, tolerance, high_quality).unwrap()
+                );
+
+                let simplifier = Simplifier::new(tolerance).radial_pass(!high_quality);
+                assert_eq!(
+                    simplifier.max_points(points.len()).simplify(&points),
+                    simplified
+                );
+                assert_eq!(simplifier.weights([1, 1]).simplify(&points), simplified);
+
+                let mut in_place = points.clone();
+                let mut stack = vec![0; points.len()];
+                let kept =
+                    simplify_in_place(&mut in_place, tolerance, high_quality, &mut stack).unwrap();
+                assert_eq!(in_place[..kept], simplified);
+            }
+        }
+
+        // Rounding the projection of (5, 1) onto the segment would measure it from (0, 0).
+        let points = [
+            Point { vec: [0i64, 0] },
+            Point { vec: [5, 1] },
+            Point { vec: [10, 0] },
+        ];
+        assert_eq!(simplify(&points, 1, true), [points[0], points[2]]);
+        assert_eq!(simplify(&points, 0, true), points);
+    }
+
+    #[test]
+    fn simplify_widens_large_integers() {
+        // Spread over most of the i32 range, so squared distances between points don't fit in an
+        // i32, and the cross products behind them don't fit in an i64.
+        let points: Vec<Point<2, i32>> = (0..2000)
+            .map(|i| Point {
+                vec: [
+                    i * 1_000_003 - 1_000_000_000,
+                    ((i * 7919) % 2003 - 1000) * 1_000_000 + (i * i) % 40_000,
+                ],
+            })
+            .collect();
+
+        for tolerance in [1, 300, 40_000] {
+            for high_quality in [true, false] {
+                let simplified = simplify(&points, tolerance, high_quality);
+                assert_eq!(
+                    simplified,
+                    simplify_checked(&points, tolerance, high_quality).unwrap()
+                );
+
+                let mut in_place = points.clone();
+                let mut stack = vec![0; points.len()];
+                let kept =
+                    simplify_in_place(&mut in_place, tolerance, high_quality, &mut stack).unwrap();
+                assert_eq!(in_place[..kept], simplified);
+            }
+        }
+
+        // (0, 3) is 3 / √2 from the diagonal.
+        let points = [
+            Point {
+                vec: [i32::MIN, i32::MIN],
+            },
+            Point { vec: [0, 3] },
+            Point {
+                vec: [i32::MAX, i32::MAX],
+            },
+        ];
+        assert_eq!(simplify(&points, 2, true), points);
+        assert_eq!(simplify(&points, 3, true), [points[0], points[2]]);
+    }
 }
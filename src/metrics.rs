@@ -0,0 +1,305 @@
+//! # Metrics
+//!
+//! Measurements of how far a simplified polyline strays from its original, for choosing a
+//! tolerance for a dataset, or asserting that simplification stays within bounds.
+//!
+//! Only available with the `std` feature.
+//!
+//! ## Example
+//! ```
+//! use simplify_polyline::{metrics, *};
+//!
+//! let points = points![(0.0, 0.0), (1.0, 0.5), (2.0, 0.0), (3.0, 0.25), (4.0, 0.0)];
+//! let simplified = simplify(&points, 1.0, true);
+//!
+//! assert_eq!(simplified, points![(0.0, 0.0), (4.0, 0.0)]);
+//! assert_eq!(metrics::max_deviation(&points, &simplified), 0.5);
+//! assert!(metrics::max_deviation(&points, &simplified) <= 1.0);
+//! ```
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::{get_sq_seg_dist, ExtendedNumOps, Point};
+
+/// The largest distance from any point of `original` to the segment of `simplified` that replaced
+/// it, which is what the `tolerance` of [simplify](crate::simplify) bounds.
+///
+/// Which point of `original` each point of `simplified` was kept from is only known by its
+/// coordinates, so where those appear more than once, this measures whichever choice of them
+/// deviates least. That is the deviation of the simplification whenever its points only appear
+/// once, and otherwise never more than it. This takes `O(n log n)` time, plus up to `O(n)` for
+/// every point of `simplified` that is repeated far apart in `original`.
+///
+/// # Panics
+///
+/// If `simplified` is not made up of points of `original`, in the same order, starting and ending
+/// with the same points.
+pub fn max_deviation<const D: usize, T: ExtendedNumOps + Float>(
+    original: &[Point<D, T>],
+    simplified: &[Point<D, T>],
+) -> T {
+    assert!(
+        original.first() == simplified.first(),
+        "the simplified polyline must start where the original does"
+    );
+    assert!(
+        original.last() == simplified.last(),
+        "the simplified polyline must end where the original does"
+    );
+
+    if original.len() < 2 || simplified.len() < 2 {
+        assert!(
+            original.len() == simplified.len(),
+            "the simplified polyline must be made up of points of the original, in order"
+        );
+        return T::zero();
+    }
+
+    // The ends are matched to each other, and every other kept point could be any point between
+    // them equal to it, so sort those by their coordinates to find them all.
+    let compare =
+        |a: &Point<D, T>, b: &Point<D, T>| a.vec.partial_cmp(&b.vec).unwrap_or(Ordering::Equal);
+    let mut order: Vec<usize> = (1..original.len() - 1).collect();
+    order.sort_by(|&a, &b| compare(&original[a], &original[b]));
+    let occurrences = |point: &Point<D, T>| {
+        let start = order.partition_point(|&i| compare(&original[i], point) == Ordering::Less);
+        let end = order.partition_point(|&i| compare(&original[i], point) != Ordering::Greater);
+        &order[start..end]
+    };
+
+    // Every point of the original the kept point so far could be, and the smallest largest
+    // squared distance of the points before it from their segments, over every way of matching
+    // the kept points before it.
+    let last = simplified.len() - 1;
+    let end = [original.len() - 1];
+    let mut matched = vec![(0, T::zero())];
+    for (k, segment) in simplified.windows(2).enumerate() {
+        let next = if k + 1 == last {
+            &end[..]
+        } else {
+            occurrences(&segment[1])
+        };
+
+        // Walk the original from the first match of this segment's start to the last of its end,
+        // tracking the best way of reaching each point with this segment.
+        let mut reached = Vec::new();
+        let mut starts = matched.iter().peekable();
+        let mut sq_dist = T::infinity();
+        let mut i = matched[0].0;
+        for &j in next.iter().filter(|&&j| j > matched[0].0) {
+            while i < j {
+                sq_dist = sq_dist.max(get_sq_seg_dist(&original[i], &segment[0], &segment[1]));
+                if let Some(&&(start, start_sq_dist)) = starts.peek() {
+                    if start == i {
+                        sq_dist = sq_dist.min(start_sq_dist);
+                        starts.next();
+                    }
+                }
+                i += 1;
+            }
+            if sq_dist < T::infinity() {
+                reached.push((j, sq_dist));
+            }
+        }
+        assert!(
+            !reached.is_empty(),
+            "the simplified polyline must be made up of points of the original, in order"
+        );
+        matched = reached;
+    }
+
+    matched[0].1.sqrt()
+}
+
+/// The discrete Hausdorff distance between two polylines: the largest distance from any point of
+/// either to the nearest point of the other.
+///
+/// This only considers their points, not the segments between them, so is never smaller than the
+/// continuous Hausdorff distance. It takes `O(n * m)` time.
+pub fn hausdorff_distance<const D: usize, T: ExtendedNumOps + Float>(
+    a: &[Point<D, T>],
+    b: &[Point<D, T>],
+) -> T {
+    let directed = |from: &[Point<D, T>], to: &[Point<D, T>]| {
+        from.iter()
+            .map(|p| to.iter().map(|q| p.sq_dist(q)).fold(T::infinity(), T::min))
+            .fold(T::zero(), T::max)
+    };
+
+    directed(a, b).max(directed(b, a)).sqrt()
+}
+
+/// The discrete Fréchet distance between two polylines: the shortest leash that lets two walkers,
+/// each stepping forwards along the points of one polyline, reach the end together.
+///
+/// Unlike [hausdorff_distance], this accounts for the order of the points, so it catches
+/// simplifications that cut across loops or switchbacks. It takes `O(n * m)` time and `O(m)`
+/// memory.
+pub fn frechet_distance<const D: usize, T: ExtendedNumOps + Float>(
+    a: &[Point<D, T>],
+    b: &[Point<D, T>],
+) -> T {
+    if a.is_empty() || b.is_empty() {
+        return T::zero();
+    }
+
+    // The shortest leash to reach `a[i]` and `b[j]`, for the current `i`, and every `j`.
+    let mut row: Vec<T> = Vec::with_capacity(b.len());
+    for (j, q) in b.iter().enumerate() {
+        let sq_dist = a[0].sq_dist(q);
+        row.push(if j == 0 {
+            sq_dist
+        } else {
+            sq_dist.max(row[j - 1])
+        });
+    }
+
+    for p in &a[1..] {
+        let mut diagonal = row[0];
+        row[0] = row[0].max(p.sq_dist(&b[0]));
+        for j in 1..b.len() {
+            let reachable = diagonal.min(row[j]).min(row[j - 1]);
+            diagonal = row[j];
+            row[j] = reachable.max(p.sq_dist(&b[j]));
+        }
+    }
+
+    row[b.len() - 1].sqrt()
+}
+
+/// The total length of a polyline.
+pub fn length<const D: usize, T: ExtendedNumOps + Float>(points: &[Point<D, T>]) -> T {
+    points.windows(2).fold(T::zero(), |length, pair| {
+        length + pair[0].sq_dist(&pair[1]).sqrt()
+    })
+}
+
+/// The length of `simplified` as a fraction of the length of `original`, which is at most 1 for
+/// any simplification in this crate. If `original` has no length, this is 1.
+pub fn length_ratio<const D: usize, T: ExtendedNumOps + Float>(
+    original: &[Point<D, T>],
+    simplified: &[Point<D, T>],
+) -> T {
+    let original_length = length(original);
+    if original_length == T::zero() {
+        return T::one();
+    }
+
+    length(simplified) / original_length
+}
+
+/// The area enclosed by a ring, which is closed implicitly if its last point is not its first.
+/// Self-intersecting rings count areas wound in opposite directions against each other.
+pub fn area<T: ExtendedNumOps + Float>(ring: &[Point<2, T>]) -> T {
+    if ring.len() < 3 {
+        return T::zero();
+    }
+
+    let mut twice_area = T::zero();
+    let mut previous = &ring[ring.len() - 1];
+    for point in ring {
+        twice_area = twice_area + (previous.vec[0] * point.vec[1] - point.vec[0] * previous.vec[1]);
+        previous = point;
+    }
+
+    (twice_area / (T::one() + T::one())).abs()
+}
+
+/// How much the area enclosed by a ring changed when simplified, as `area(simplified) -
+/// area(original)`, so negative if it shrank.
+pub fn area_change<T: ExtendedNumOps + Float>(
+    original: &[Point<2, T>],
+    simplified: &[Point<2, T>],
+) -> T {
+    area(simplified) - area(original)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::{simplify, simplify_lang};
+
+    fn points(coords: &[(f64, f64)]) -> Vec<Point<2, f64>> {
+        coords.iter().map(|&(x, y)| Point { vec: [x, y] }).collect()
+    }
+
+    #[test]
+    fn deviation_is_bounded_by_tolerance() {
+        let original: Vec<Point<2, f64>> = (0..500)
+            .map(|i| {
+                let x = i as f64 * 0.1;
+                Point {
+                    vec: [x, x.sin() * 4.0],
+                }
+            })
+            .collect();
+
+        for tolerance in [0.1, 0.5, 2.0] {
+            for high_quality in [true, false] {
+                let simplified = simplify(&original, tolerance, high_quality);
+                let deviation = max_deviation(&original, &simplified);
+                assert!(deviation > 0.0 && deviation <= tolerance);
+                assert!(hausdorff_distance(&original, &simplified) >= deviation);
+                assert!(frechet_distance(&original, &simplified) >= deviation);
+                assert!(length_ratio(&original, &simplified) <= 1.0);
+            }
+        }
+        assert_eq!(max_deviation(&original, &original), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn deviation_needs_a_subsequence() {
+        let original = points(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]);
+        max_deviation(&original, &points(&[(0.0, 0.0), (1.0, 2.0), (2.0, 0.0)]));
+    }
+
+    #[test]
+    fn deviation_of_a_ring() {
+        let ring = points(&[(0.0, 0.0), (5.0, 0.0), (5.0, 5.0), (0.0, 0.0)]);
+        let simplified = simplify(&ring, 10.0, true);
+        assert_eq!(simplified, points(&[(0.0, 0.0), (0.0, 0.0)]));
+        assert_eq!(max_deviation(&ring, &simplified), 50.0.sqrt());
+    }
+
+    #[test]
+    fn deviation_of_repeated_points() {
+        // The second (4, 0) is kept, so (2, 0.4) is measured against the segment to it, not the
+        // one to (4, -10) that keeping the first point equal to it would leave.
+        let original = points(&[(0.0, 0.0), (4.0, 0.0), (2.0, 0.4), (4.0, 0.0), (4.0, -10.0)]);
+        let simplified = simplify_lang(&original, 0.5, 4);
+        assert_eq!(simplified, points(&[(0.0, 0.0), (4.0, 0.0), (4.0, -10.0)]));
+        assert_eq!(max_deviation(&original, &simplified), 0.4);
+    }
+
+    #[test]
+    fn hausdorff_and_frechet() {
+        let a = points(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+        let b = points(&[(0.0, 1.0), (2.0, 1.0)]);
+        assert_eq!(hausdorff_distance(&a, &b), 2.0.sqrt());
+        assert_eq!(frechet_distance(&a, &b), 2.0.sqrt());
+
+        // The same points, visited in the opposite order.
+        let reversed: Vec<_> = a.iter().rev().copied().collect();
+        assert_eq!(hausdorff_distance(&a, &reversed), 0.0);
+        assert_eq!(frechet_distance(&a, &reversed), 2.0);
+    }
+
+    #[test]
+    fn lengths_and_areas() {
+        let square = points(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0), (0.0, 0.0)]);
+        let triangle = points(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 0.0)]);
+
+        assert_eq!(length(&square), 8.0);
+        assert_eq!(length_ratio(&square, &triangle), (4.0 + 8.0.sqrt()) / 8.0);
+        assert_eq!(area(&square), 4.0);
+        assert_eq!(area(&square[..4]), 4.0);
+        assert_eq!(area_change(&square, &triangle), -2.0);
+        assert_eq!(length_ratio(&square[..1], &square[..1]), 1.0);
+    }
+}
@@ -0,0 +1,74 @@
+use mint::{Point2, Point3, Vector2, Vector3};
+
+use crate::{as_points::cast_points, AsPoints, ExtendedNumOps, Point, Vertex};
+
+macro_rules! impl_mint {
+    ($vec:ident, $d:literal, [$($field:ident),+]) => {
+        impl<T: ExtendedNumOps> From<$vec<T>> for Point<$d, T> {
+            fn from(vec: $vec<T>) -> Self {
+                Point {
+                    vec: [$(vec.$field),+],
+                }
+            }
+        }
+
+        impl<T: ExtendedNumOps> From<Point<$d, T>> for $vec<T> {
+            fn from(point: Point<$d, T>) -> Self {
+                let [$($field),+] = point.vec;
+                $vec { $($field),+ }
+            }
+        }
+
+        impl<T: ExtendedNumOps> Vertex<$d, T> for $vec<T> {
+            #[inline(always)]
+            fn coords(&self) -> [T; $d] {
+                [$(self.$field),+]
+            }
+        }
+
+        impl<T: ExtendedNumOps> AsPoints<$d, T> for [$vec<T>] {
+            fn as_points(&self) -> &[Point<$d, T>] {
+                // SAFETY: this `mint` type is a `repr(C)` struct of one field per component.
+                unsafe { cast_points(self) }
+            }
+        }
+    };
+}
+
+impl_mint!(Vector2, 2, [x, y]);
+impl_mint!(Vector3, 3, [x, y, z]);
+impl_mint!(Point2, 2, [x, y]);
+impl_mint!(Point3, 3, [x, y, z]);
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use mint::{Point2, Vector3};
+
+    use crate::{simplify, AsPoints, Point};
+
+    #[test]
+    fn simplifies_mint_slices() {
+        let vecs: Vec<Point2<f64>> = (0..50)
+            .map(|i| Point2 {
+                x: i as f64,
+                y: (i as f64 * 0.7).sin() * 4.0,
+            })
+            .collect();
+        let points: Vec<Point<2, f64>> = vecs.iter().map(|&v| v.into()).collect();
+
+        assert_eq!(
+            simplify(vecs.as_points(), 1.0, false),
+            simplify(&points, 1.0, false)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let vec = Vector3 { x: 1, y: 2, z: 3 };
+        let point: Point<3, i32> = vec.into();
+        assert_eq!(point.vec, [1, 2, 3]);
+        assert_eq!(Vector3::from(point), vec);
+    }
+}
@@ -0,0 +1,85 @@
+use nalgebra::{SVector, Scalar};
+
+use crate::{as_points::cast_points, AsPoints, ExtendedNumOps, Point, Vertex};
+
+impl<const D: usize, T: Scalar + ExtendedNumOps> From<SVector<T, D>> for Point<D, T> {
+    fn from(vec: SVector<T, D>) -> Self {
+        Point { vec: vec.into() }
+    }
+}
+
+impl<const D: usize, T: Scalar + ExtendedNumOps> From<Point<D, T>> for SVector<T, D> {
+    fn from(point: Point<D, T>) -> Self {
+        SVector::from(point.vec)
+    }
+}
+
+impl<const D: usize, T: Scalar + ExtendedNumOps> From<nalgebra::Point<T, D>> for Point<D, T> {
+    fn from(point: nalgebra::Point<T, D>) -> Self {
+        point.coords.into()
+    }
+}
+
+impl<const D: usize, T: Scalar + ExtendedNumOps> From<Point<D, T>> for nalgebra::Point<T, D> {
+    fn from(point: Point<D, T>) -> Self {
+        nalgebra::Point::from(point.vec)
+    }
+}
+
+impl<const D: usize, T: Scalar + ExtendedNumOps> Vertex<D, T> for SVector<T, D> {
+    #[inline(always)]
+    fn coords(&self) -> [T; D] {
+        (*self).into()
+    }
+}
+
+impl<const D: usize, T: Scalar + ExtendedNumOps> Vertex<D, T> for nalgebra::Point<T, D> {
+    #[inline(always)]
+    fn coords(&self) -> [T; D] {
+        self.coords.coords()
+    }
+}
+
+impl<const D: usize, T: Scalar + ExtendedNumOps> AsPoints<D, T> for [SVector<T, D>] {
+    fn as_points(&self) -> &[Point<D, T>] {
+        // SAFETY: a statically sized column vector is a `repr(C)` wrapper around `[[T; D]; 1]`.
+        unsafe { cast_points(self) }
+    }
+}
+
+impl<const D: usize, T: Scalar + ExtendedNumOps> AsPoints<D, T> for [nalgebra::Point<T, D>] {
+    fn as_points(&self) -> &[Point<D, T>] {
+        // SAFETY: a point is a `repr(C)` wrapper around a statically sized column vector.
+        unsafe { cast_points(self) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use nalgebra::{Point3, Vector2};
+
+    use crate::{simplify, AsPoints, Point};
+
+    #[test]
+    fn simplifies_nalgebra_slices() {
+        let vecs: Vec<Vector2<f64>> = (0..50)
+            .map(|i| Vector2::new(i as f64, (i as f64 * 0.7).sin() * 4.0))
+            .collect();
+        let points: Vec<Point<2, f64>> = vecs.iter().map(|&v| v.into()).collect();
+
+        assert_eq!(
+            simplify(vecs.as_points(), 1.0, false),
+            simplify(&points, 1.0, false)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let vec = Point3::new(1.0, 2.0, 3.0);
+        let point: Point<3, f64> = vec.into();
+        assert_eq!(point.vec, [1.0, 2.0, 3.0]);
+        assert_eq!(Point3::from(point), vec);
+    }
+}
@@ -0,0 +1,149 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{sq_seg_dist, ExtendedNumOps, Point, SqDist};
+
+/// Whether every point between `first` and `last` is within tolerance of the segment between them.
+fn is_shortcut<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    first: usize,
+    last: usize,
+    tolerance: T,
+) -> bool {
+    let tolerance = SqDist::new(tolerance);
+    (first + 1..last).all(|i| sq_seg_dist(&points[i], &points[first], &points[last]) <= tolerance)
+}
+
+/// Simplifies a polyline within a given tolerance, keeping the fewest points possible, using the
+/// Imai–Iri algorithm.
+///
+/// Every pair of points whose segment is within `tolerance` of all the points between them is a
+/// shortcut, and the simplified polyline is the path from the first point to the last using the
+/// fewest shortcuts. This measures distance the same way as [simplify](crate::simplify), so it
+/// never keeps more points than Douglas–Peucker would for the same tolerance, and often keeps
+/// fewer.
+///
+/// # Complexity
+///
+/// This takes `O(n³)` time in the worst case and `O(n)` memory, as each of the `O(n²)` possible
+/// shortcuts takes `O(n)` to check. Shortcuts that can't improve on the best path found so far
+/// are skipped, which helps a lot in practice, but this is still only suitable for polylines of
+/// up to a few thousand points. For anything larger, simplify with [simplify](crate::simplify)
+/// first, or split the polyline up.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let points = points![(0.0, 2.0), (1.0, 0.0), (2.0, 3.0), (3.0, 1.0), (4.0, 4.0), (5.0, 3.0)];
+///
+/// // Douglas–Peucker can't drop anything here, as it always splits at the furthest point first.
+/// assert_eq!(simplify(&points, 1.2, true), points);
+/// assert_eq!(
+///     simplify_optimal(&points, 1.2),
+///     points![(0.0, 2.0), (1.0, 0.0), (4.0, 4.0), (5.0, 3.0)]
+/// );
+/// ```
+pub fn simplify_optimal<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    tolerance: T,
+) -> Vec<Point<D, T>> {
+    optimal_indices(points, tolerance)
+        .into_iter()
+        .map(|i| points[i])
+        .collect()
+}
+
+/// Same as [simplify_optimal], but returns the indices of the kept points.
+pub(crate) fn optimal_indices<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    tolerance: T,
+) -> Vec<usize> {
+    if points.len() <= 2 {
+        return (0..points.len()).collect();
+    }
+
+    let tolerance_sq = tolerance * tolerance;
+    // The fewest shortcuts needed to reach each point from the first, and the point before it.
+    let mut hops = vec![0; points.len()];
+    let mut previous = vec![0; points.len()];
+
+    for last in 1..points.len() {
+        let mut best = usize::MAX;
+        for (first, &first_hops) in hops[..last].iter().enumerate() {
+            if first_hops + 1 < best && is_shortcut(points, first, last, tolerance_sq) {
+                best = first_hops + 1;
+                previous[last] = first;
+            }
+        }
+        hops[last] = best;
+    }
+
+    let mut index = points.len() - 1;
+    let mut kept = vec![index];
+    while index > 0 {
+        index = previous[index];
+        kept.push(index);
+    }
+    kept.reverse();
+
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_shortcut, optimal_indices, simplify_optimal};
+    use crate::{points, simplify, Point};
+
+    #[test]
+    fn never_keeps_more_than_douglas_peucker() {
+        // A meandering river.
+        let points = points![
+            (0.0, 0.0),
+            (1.0, 1.5),
+            (2.0, 2.2),
+            (3.0, 2.0),
+            (4.0, 0.9),
+            (5.0, -0.4),
+            (6.0, -1.1),
+            (7.0, -0.8),
+            (8.0, 0.3),
+            (9.0, 1.0),
+            (10.0, 0.8),
+            (11.0, 0.2),
+            (12.0, -0.1),
+            (13.0, 0.0)
+        ];
+        for tolerance in [0.1, 0.5, 2.0] {
+            let kept = optimal_indices(&points, tolerance);
+            assert!(kept.len() <= simplify(&points, tolerance, true).len());
+            assert_eq!(kept.first(), Some(&0));
+            assert_eq!(kept.last(), Some(&(points.len() - 1)));
+            for pair in kept.windows(2) {
+                assert!(is_shortcut(
+                    &points,
+                    pair[0],
+                    pair[1],
+                    tolerance * tolerance
+                ));
+            }
+        }
+        assert_eq!(optimal_indices(&points, 2.0), [0, 1, 13]);
+    }
+
+    #[test]
+    fn beats_douglas_peucker() {
+        // Douglas–Peucker splits at (2, -2), the first of three points 2 from the segment between
+        // the ends, and can't drop anything after that, but (2, -2) can be dropped instead.
+        let points = points![(0, 0), (2, -2), (4, -2), (6, 2), (8, 0)];
+        assert_eq!(simplify(&points, 1, true), points);
+        assert_eq!(optimal_indices(&points, 1), [0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn short_polylines_are_unchanged() {
+        let points = [Point { vec: [0, 0] }, Point { vec: [3, 4] }];
+        for len in 0..=2 {
+            assert_eq!(simplify_optimal(&points[..len], 1), &points[..len]);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use alloc::{vec, vec::Vec};
+use rayon::prelude::*;
+
+use crate::{
+    max_sq_seg_dist, simplify_dp_step, simplify_radial_dist, sq_seg_dist, ExtendedNumOps, Point,
+    SqDist,
+};
+
+/// Ranges with fewer points than this are simplified with the sequential recursion, as splitting
+/// them any further costs more than it saves.
+const PAR_MIN_RANGE: usize = 1 << 12;
+
+/// Ranges with more points than this have their max-distance scan split across threads too.
+const PAR_MIN_SCAN: usize = 1 << 16;
+
+fn max_sq_seg_dist_par<const D: usize, T: ExtendedNumOps + Send + Sync>(
+    points: &[Point<D, T>],
+    first: usize,
+    last: usize,
+    tolerance: T,
+) -> (usize, SqDist<T>) {
+    (first + 1..last)
+        .into_par_iter()
+        .map(|i| (i, sq_seg_dist(&points[i], &points[first], &points[last])))
+        // Ties go to the lower index, so the chosen point is the same one the sequential scan
+        // would have found.
+        .reduce(
+            || (0, SqDist::new(tolerance)),
+            |a, b| {
+                if b.1 > a.1 || (b.1 == a.1 && b.0 < a.0) {
+                    b
+                } else {
+                    a
+                }
+            },
+        )
+}
+
+fn simplify_dp_step_par<const D: usize, T: ExtendedNumOps + Send + Sync>(
+    points: &[Point<D, T>],
+    first: usize,
+    last: usize,
+    tolerance: T,
+) -> Vec<Point<D, T>> {
+    let mut simplified = Vec::new();
+
+    if last - first < PAR_MIN_RANGE {
+        simplify_dp_step(points, first, last, tolerance, &mut simplified);
+        return simplified;
+    }
+
+    let (max_index, max_sq_dist) = if last - first < PAR_MIN_SCAN {
+        max_sq_seg_dist(points, first, last, tolerance)
+    } else {
+        max_sq_seg_dist_par(points, first, last, tolerance)
+    };
+
+    if max_sq_dist > SqDist::new(tolerance) {
+        let (left, right) = rayon::join(
+            || simplify_dp_step_par(points, first, max_index, tolerance),
+            || simplify_dp_step_par(points, max_index, last, tolerance),
+        );
+        simplified.reserve(left.len() + right.len() + 1);
+        simplified.extend(left);
+        simplified.push(points[max_index]);
+        simplified.extend(right);
+    }
+
+    simplified
+}
+
+fn simplify_douglas_peucker_par<const D: usize, T: ExtendedNumOps + Send + Sync>(
+    points: &[Point<D, T>],
+    tolerance: T,
+) -> Vec<Point<D, T>> {
+    let mut simplified = vec![points[0]];
+    simplified.extend(simplify_dp_step_par(points, 0, points.len() - 1, tolerance));
+    simplified.push(points[points.len() - 1]);
+
+    simplified
+}
+
+/// Simplifies a polyline within a given tolerance, using the [rayon] thread pool.
+///
+/// This produces exactly the same points as [simplify](crate::simplify). The two halves left by
+/// each Douglas–Peucker split are simplified in parallel, and for very large ranges the search
+/// for the farthest point is parallelized as well. Small inputs gain nothing from this, and are
+/// simplified sequentially.
+///
+/// Only available with the `rayon` feature.
+pub fn simplify_par<const D: usize, T: ExtendedNumOps + Send + Sync>(
+    points: &[Point<D, T>],
+    tolerance: T,
+    high_quality: bool,
+) -> Vec<Point<D, T>> {
+    if points.len() <= 2 {
+        return points.to_vec();
+    }
+
+    let tolerance_sq = tolerance * tolerance;
+    if high_quality {
+        simplify_douglas_peucker_par(points, tolerance_sq)
+    } else {
+        simplify_douglas_peucker_par(&simplify_radial_dist(points, tolerance_sq), tolerance_sq)
+    }
+}
+
+/// Simplifies many polylines within a given tolerance, using the [rayon] thread pool.
+///
+/// Each polyline is simplified as if by [simplify_par], and the results are returned in the same
+/// order as `polylines`. Work is spread over whichever pool this is called from, so wrap the call
+/// in [rayon::ThreadPool::install] to use a pool other than the global one.
+///
+/// Only available with the `rayon` feature.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let polylines = vec![
+///     vec![point!(0.0, 0.0), point!(1.0, 1.0), point!(2.0, 2.0)],
+///     vec![point!(0.0, 0.0), point!(1.0, 5.0), point!(2.0, 0.0)],
+/// ];
+///
+/// let simplified = simplify_many(&polylines, 1.0, true);
+/// assert_eq!(simplified[0], points![(0.0, 0.0), (2.0, 2.0)]);
+/// assert_eq!(simplified[1], polylines[1]);
+/// ```
+pub fn simplify_many<const D: usize, T, P>(
+    polylines: &[P],
+    tolerance: T,
+    high_quality: bool,
+) -> Vec<Vec<Point<D, T>>>
+where
+    T: ExtendedNumOps + Send + Sync,
+    P: AsRef<[Point<D, T>]> + Sync,
+{
+    polylines
+        .par_iter()
+        .map(|polyline| simplify_par(polyline.as_ref(), tolerance, high_quality))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{points, simplify, simplify_many, simplify_par, Point};
+
+    #[test]
+    fn matches_simplify() {
+        // A parabola long enough for both the split and the scan to run in parallel. Every range is
+        // split at its middle, and where that falls between two points, they tie.
+        let points: Vec<Point<2, f64>> = (0..100_001)
+            .map(|i| {
+                let x = i as f64 - 50_000.0;
+                Point {
+                    vec: [x, x * x / 2500.0],
+                }
+            })
+            .collect();
+        for high_quality in [true, false] {
+            assert_eq!(
+                simplify_par(&points, 1.0, high_quality),
+                simplify(&points, 1.0, high_quality)
+            );
+        }
+    }
+
+    #[test]
+    fn many_matches_simplify() {
+        let polylines: Vec<Vec<Point<2, f64>>> = (2..200)
+            .map(|len| {
+                (0..len)
+                    .map(|i| Point {
+                        vec: [i as f64 * 0.5, (i % 7) as f64 * 0.4],
+                    })
+                    .collect()
+            })
+            .collect();
+        let expected: Vec<_> = polylines
+            .iter()
+            .map(|polyline| simplify(polyline, 1.0, false))
+            .collect();
+        assert_eq!(simplify_many(&polylines, 1.0, false), expected);
+    }
+
+    #[test]
+    fn short_input() {
+        let points = points![(0.0, 0.0), (3.0, 4.0)];
+        assert_eq!(simplify_par(&points, 1.0, true), points);
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::{Add, Mul, Sub};
+use core::ops::{Add, Mul, Sub};
 
 use crate::ExtendedNumOps;
 
@@ -11,6 +11,9 @@ use crate::ExtendedNumOps;
 /// - component-wise multiplication
 /// - scalar multiplication
 ///
+/// A point has exactly the same memory layout as `[T; D]`, which is what lets
+/// [AsPoints](crate::AsPoints) view slices of other vector types as points without copying them.
+///
 /// ## Example
 /// ```
 /// use simplify_polyline::*;
@@ -24,6 +27,7 @@ use crate::ExtendedNumOps;
 /// assert_eq!(point2d * 7, Point { vec: [7, 7] });
 /// ```
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(transparent)]
 pub struct Point<const D: usize, T: ExtendedNumOps> {
     /// The components of the point.
     pub vec: [T; D],
@@ -117,6 +121,18 @@ impl<const D: usize, T: ExtendedNumOps> Point<D, T> {
     }
 }
 
+impl<const D: usize, T: ExtendedNumOps> From<[T; D]> for Point<D, T> {
+    fn from(vec: [T; D]) -> Self {
+        Point { vec }
+    }
+}
+
+impl<const D: usize, T: ExtendedNumOps> From<Point<D, T>> for [T; D] {
+    fn from(point: Point<D, T>) -> Self {
+        point.vec
+    }
+}
+
 macro_rules! impl_ref_op {
     (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
         impl<'a, const D: usize, T: ExtendedNumOps> $imp<$u> for &'a $t {
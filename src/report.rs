@@ -0,0 +1,255 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use num_traits::Float;
+
+use crate::{ExtendedNumOps, Metric, Point, Simplifier, SqDist};
+
+/// Statistics about a single simplification, returned by [simplify_with_report] and
+/// [Simplifier::simplify_with_report].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SimplifyReport<T> {
+    /// The number of points passed in.
+    pub input_len: usize,
+    /// The number of points left after the radial distance pass, which is the same as
+    /// `input_len` when simplifying with `high_quality`, or without [Simplifier::radial_pass].
+    pub radial_len: usize,
+    /// The number of points left after the algorithm, which were returned.
+    pub output_len: usize,
+    /// The largest squared distance from any dropped point to the segment of the result that
+    /// replaced it, measured like the tolerance is, so with [Simplifier::weights] and
+    /// [Simplifier::metric] applied, and rounded up for integer types. This includes points
+    /// dropped by the radial distance pass, which can be as far as the radial tolerance and the
+    /// algorithm's combined. See [max_deviation](SimplifyReport::max_deviation) for the distance
+    /// itself.
+    ///
+    /// Without a radial distance pass, dropped points are within the tolerance, so this always
+    /// fits in `T`. With one, or with [Simplifier::max_points], simplifying with a report panics
+    /// if it doesn't.
+    pub max_sq_deviation: T,
+    /// How deeply Douglas–Peucker split the polyline, which is 0 if it only kept the first and
+    /// last points. Always 0 for other algorithms.
+    pub depth: usize,
+}
+
+impl<T: ExtendedNumOps> SimplifyReport<T> {
+    pub(crate) fn unchanged(len: usize) -> Self {
+        SimplifyReport {
+            input_len: len,
+            radial_len: len,
+            output_len: len,
+            max_sq_deviation: T::zero(),
+            depth: 0,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ExtendedNumOps + Float> SimplifyReport<T> {
+    /// The largest distance from any dropped point to the segment of the result that replaced it,
+    /// in the same units as the tolerance, so never more than it without a radial distance pass.
+    ///
+    /// Only available with the `std` feature.
+    pub fn max_deviation(&self) -> T {
+        self.max_sq_deviation.sqrt()
+    }
+}
+
+/// The largest squared distance, measured with `metric`, from any point of `points` not in `kept`
+/// to the segment between the kept points either side of it.
+pub(crate) fn max_sq_dropped_dist<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    kept: &[usize],
+    metric: Metric,
+) -> T {
+    let mut max_sq_dist = SqDist::new(T::zero());
+    for pair in kept.windows(2) {
+        for point in &points[pair[0] + 1..pair[1]] {
+            let sq_dist = metric
+                .sq_dist(point, &points[pair[0]], &points[pair[1]])
+                .rounded_up();
+            if sq_dist > max_sq_dist {
+                max_sq_dist = sq_dist;
+            }
+        }
+    }
+
+    max_sq_dist.value()
+}
+
+/// Simplifies a polyline within a given tolerance, exactly like [simplify](crate::simplify), and
+/// also reports how many points each pass kept, how far dropped points were from the result, and
+/// how deeply Douglas–Peucker recursed.
+///
+/// This costs little more than [simplify](crate::simplify) itself, as everything reported is
+/// measured during simplification anyway, except how far the points dropped by the radial
+/// distance pass are, which takes one more pass over the polyline without `high_quality`. To
+/// report on a simplification with other options, use
+/// [Simplifier::simplify_with_report].
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let points = points![(0.0, 0.0), (1.0, 0.5), (2.0, 0.0), (3.0, 3.0), (4.0, 0.0)];
+/// let (simplified, report) = simplify_with_report(&points, 1.0, true);
+///
+/// assert_eq!(simplified, points![(0.0, 0.0), (2.0, 0.0), (3.0, 3.0), (4.0, 0.0)]);
+/// assert_eq!(report.input_len, 5);
+/// assert_eq!(report.output_len, 4);
+/// assert_eq!(report.max_sq_deviation, 0.25);
+/// assert_eq!(report.depth, 2);
+/// ```
+pub fn simplify_with_report<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    tolerance: T,
+    high_quality: bool,
+) -> (Vec<Point<D, T>>, SimplifyReport<T>) {
+    Simplifier::new(tolerance)
+        .radial_pass(!high_quality)
+        .simplify_with_report(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{simplify_with_report, SimplifyReport};
+    use crate::{get_sq_seg_dist, simplify, Algorithm, Point, Simplifier};
+
+    fn trail() -> [Point<2, f64>; 12] {
+        [
+            (0.0, 0.0),
+            (1.0, 0.2),
+            (2.0, -0.2),
+            (3.0, 2.0),
+            (4.0, 2.2),
+            (5.0, 1.9),
+            (6.0, 4.0),
+            (7.0, 3.8),
+            (8.0, 0.5),
+            (9.0, 0.4),
+            (10.0, 0.6),
+            (11.0, 0.0),
+        ]
+        .map(|(x, y)| Point { vec: [x, y] })
+    }
+
+    fn kept(points: &[Point<2, f64>], indices: &[usize]) -> Vec<Point<2, f64>> {
+        indices.iter().map(|&i| points[i]).collect()
+    }
+
+    #[test]
+    fn matches_simplify() {
+        let points = trail();
+        for tolerance in [0.3, 0.5, 1.0] {
+            for high_quality in [true, false] {
+                let (simplified, report) = simplify_with_report(&points, tolerance, high_quality);
+                assert_eq!(simplified, simplify(&points, tolerance, high_quality));
+                assert_eq!(report.input_len, points.len());
+                assert_eq!(report.radial_len, points.len());
+                assert_eq!(report.output_len, simplified.len());
+                assert!(report.max_sq_deviation <= tolerance * tolerance);
+            }
+        }
+    }
+
+    #[test]
+    fn deviation_is_the_largest_dropped() {
+        let points = trail();
+        let (simplified, report) = simplify_with_report(&points, 0.5, true);
+        assert_eq!(simplified, kept(&points, &[0, 2, 3, 5, 6, 7, 8, 11]));
+        assert_eq!(
+            report.max_sq_deviation,
+            get_sq_seg_dist(&points[10], &points[8], &points[11])
+        );
+        assert_eq!(report.depth, 4);
+
+        let (simplified, report) = simplify_with_report(&points, 1.0, true);
+        assert_eq!(simplified, kept(&points, &[0, 2, 6, 8, 11]));
+        assert_eq!(report.depth, 2);
+    }
+
+    #[test]
+    fn deviation_includes_the_radial_pass() {
+        // (0.5, 0.75) is within the tolerance of the first point, so the radial pass drops it
+        // before Douglas–Peucker sees it, but it is still that far from the result.
+        let points =
+            [(0.0, 0.0), (0.5, 0.75), (4.0, 0.0), (8.0, 0.0)].map(|(x, y)| Point { vec: [x, y] });
+        for high_quality in [true, false] {
+            let (simplified, report) = simplify_with_report(&points, 1.0, high_quality);
+            assert_eq!(simplified, kept(&points, &[0, 3]));
+            assert_eq!(report.radial_len, if high_quality { 4 } else { 3 });
+            assert_eq!(report.max_sq_deviation, 0.75 * 0.75);
+        }
+    }
+
+    #[test]
+    fn respects_simplifier_options() {
+        let points = trail();
+
+        let (simplified, report) = Simplifier::new(0.5)
+            .max_points(4)
+            .simplify_with_report(&points);
+        assert_eq!(simplified, kept(&points, &[0, 6, 8, 11]));
+        assert_eq!(report.depth, 2);
+        assert_eq!(
+            report.max_sq_deviation,
+            get_sq_seg_dist(&points[2], &points[0], &points[6])
+        );
+        assert!(report.max_sq_deviation > 0.5 * 0.5);
+
+        // Without a limit that is reached, splitting the furthest range first is just as deep.
+        for simplifier in [
+            Simplifier::new(0.5).max_points(points.len()),
+            Simplifier::new(0.5).min_points(3),
+        ] {
+            assert_eq!(
+                simplifier.simplify_with_report(&points),
+                simplify_with_report(&points, 0.5, true)
+            );
+        }
+
+        let (simplified, report) = Simplifier::new(0.5)
+            .algorithm(Algorithm::ReumannWitkam)
+            .simplify_with_report(&points);
+        assert_eq!(simplified, kept(&points, &[0, 1, 2, 3, 5, 6, 7, 8, 11]));
+        assert_eq!(report.depth, 0);
+        assert_eq!(
+            report.max_sq_deviation,
+            get_sq_seg_dist(&points[10], &points[8], &points[11])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn deviation_in_tolerance_units() {
+        let points = [
+            Point { vec: [0.0, 0.0] },
+            Point { vec: [1.0, 0.6] },
+            Point { vec: [2.0, 0.0] },
+        ];
+        let (_, report) = simplify_with_report(&points, 1.0, true);
+        assert_eq!(report.max_sq_deviation, 0.36);
+        assert_eq!(report.max_deviation(), 0.6);
+    }
+
+    #[test]
+    fn short_polylines_are_unchanged() {
+        let points = [Point { vec: [0, 0] }, Point { vec: [3, 4] }];
+        for len in 0..=2 {
+            let (simplified, report) = simplify_with_report(&points[..len], 1, true);
+            assert_eq!(simplified, &points[..len]);
+            assert_eq!(
+                report,
+                SimplifyReport {
+                    input_len: len,
+                    radial_len: len,
+                    output_len: len,
+                    max_sq_deviation: 0,
+                    depth: 0,
+                }
+            );
+        }
+    }
+}
@@ -26,14 +26,15 @@
 //! ```
 
 use crate::{ExtendedNumOps, Point};
+use alloc::string::String;
+use core::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+};
 use serde::{
     de::{self, MapAccess, Visitor},
     Deserialize,
 };
-use std::{
-    fmt::{self, Formatter},
-    marker::PhantomData,
-};
 
 struct Point2DVisitor<T>(PhantomData<T>);
 
@@ -0,0 +1,547 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::{One, Zero};
+
+use crate::{get_sq_seg_dist, max_sq_seg_dist, simplify_radial_dist, ExtendedNumOps, Point};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Floating point types that have a vectorized segment distance scan, for use with
+/// [simplify_simd](crate::simplify_simd).
+///
+/// This is implemented for [f32] and [f64], and cannot be implemented outside of this crate.
+pub trait SimdFloat: ExtendedNumOps + sealed::Sealed {
+    #[doc(hidden)]
+    fn max_sq_seg_dist(
+        points: &[Point<2, Self>],
+        first: usize,
+        last: usize,
+        tolerance: Self,
+    ) -> (usize, Self);
+}
+
+/// The most lanes any [Lanes] has.
+const MAX_LANES: usize = 4;
+
+/// A vector of coordinates, one per lane, with just the operations the scan needs.
+///
+/// Every method is unsafe, as the instructions they use may not be available on every target.
+trait Lanes: Copy {
+    type Scalar: SimdFloat;
+    /// A mask with each lane either all ones or all zeros, as comparisons return.
+    type Mask: Copy;
+    const LANES: usize;
+
+    unsafe fn splat(value: Self::Scalar) -> Self;
+    /// Loads the `x` and `y` coordinates of the first `LANES` points, of which there must be at
+    /// least that many.
+    unsafe fn load(points: &[Point<2, Self::Scalar>]) -> (Self, Self);
+    unsafe fn add(self, other: Self) -> Self;
+    unsafe fn sub(self, other: Self) -> Self;
+    unsafe fn mul(self, other: Self) -> Self;
+    unsafe fn div(self, other: Self) -> Self;
+    /// Which lanes are greater than the same lanes of `other`, which none are if either is NaN.
+    unsafe fn gt(self, other: Self) -> Self::Mask;
+    /// The lanes of `a` where `mask` is set, and of `b` where it isn't.
+    unsafe fn select(mask: Self::Mask, a: Self, b: Self) -> Self;
+    /// A bit for each lane, set where `mask` is, with the first lane in the lowest bit.
+    unsafe fn bits(mask: Self::Mask) -> u32;
+    /// Stores every lane in `out`, which must have room for them.
+    unsafe fn store(self, out: &mut [Self::Scalar]);
+}
+
+/// SSE2 is part of the x86_64 baseline, so these are always available there.
+#[cfg(target_arch = "x86_64")]
+mod sse2 {
+    use core::arch::x86_64::*;
+
+    use super::Lanes;
+    use crate::Point;
+
+    #[derive(Clone, Copy)]
+    pub(super) struct F32x4(__m128);
+
+    impl Lanes for F32x4 {
+        type Scalar = f32;
+        type Mask = __m128;
+        const LANES: usize = 4;
+
+        #[inline(always)]
+        unsafe fn splat(value: f32) -> Self {
+            F32x4(_mm_set1_ps(value))
+        }
+
+        #[inline(always)]
+        unsafe fn load(points: &[Point<2, f32>]) -> (Self, Self) {
+            // Points are laid out as their coordinates, so four of them are x, y, x, y, ...
+            let coords = points.as_ptr() as *const f32;
+            let (a, b) = (_mm_loadu_ps(coords), _mm_loadu_ps(coords.add(4)));
+            (
+                F32x4(_mm_shuffle_ps(a, b, 0b10_00_10_00)),
+                F32x4(_mm_shuffle_ps(a, b, 0b11_01_11_01)),
+            )
+        }
+
+        #[inline(always)]
+        unsafe fn add(self, other: Self) -> Self {
+            F32x4(_mm_add_ps(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn sub(self, other: Self) -> Self {
+            F32x4(_mm_sub_ps(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn mul(self, other: Self) -> Self {
+            F32x4(_mm_mul_ps(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn div(self, other: Self) -> Self {
+            F32x4(_mm_div_ps(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn gt(self, other: Self) -> __m128 {
+            _mm_cmpgt_ps(self.0, other.0)
+        }
+
+        #[inline(always)]
+        unsafe fn select(mask: __m128, a: Self, b: Self) -> Self {
+            F32x4(_mm_or_ps(_mm_and_ps(mask, a.0), _mm_andnot_ps(mask, b.0)))
+        }
+
+        #[inline(always)]
+        unsafe fn bits(mask: __m128) -> u32 {
+            _mm_movemask_ps(mask) as u32
+        }
+
+        #[inline(always)]
+        unsafe fn store(self, out: &mut [f32]) {
+            _mm_storeu_ps(out[..4].as_mut_ptr(), self.0)
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    pub(super) struct F64x2(__m128d);
+
+    impl Lanes for F64x2 {
+        type Scalar = f64;
+        type Mask = __m128d;
+        const LANES: usize = 2;
+
+        #[inline(always)]
+        unsafe fn splat(value: f64) -> Self {
+            F64x2(_mm_set1_pd(value))
+        }
+
+        #[inline(always)]
+        unsafe fn load(points: &[Point<2, f64>]) -> (Self, Self) {
+            let coords = points.as_ptr() as *const f64;
+            let (a, b) = (_mm_loadu_pd(coords), _mm_loadu_pd(coords.add(2)));
+            (F64x2(_mm_unpacklo_pd(a, b)), F64x2(_mm_unpackhi_pd(a, b)))
+        }
+
+        #[inline(always)]
+        unsafe fn add(self, other: Self) -> Self {
+            F64x2(_mm_add_pd(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn sub(self, other: Self) -> Self {
+            F64x2(_mm_sub_pd(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn mul(self, other: Self) -> Self {
+            F64x2(_mm_mul_pd(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn div(self, other: Self) -> Self {
+            F64x2(_mm_div_pd(self.0, other.0))
+        }
+
+        #[inline(always)]
+        unsafe fn gt(self, other: Self) -> __m128d {
+            _mm_cmpgt_pd(self.0, other.0)
+        }
+
+        #[inline(always)]
+        unsafe fn select(mask: __m128d, a: Self, b: Self) -> Self {
+            F64x2(_mm_or_pd(_mm_and_pd(mask, a.0), _mm_andnot_pd(mask, b.0)))
+        }
+
+        #[inline(always)]
+        unsafe fn bits(mask: __m128d) -> u32 {
+            _mm_movemask_pd(mask) as u32
+        }
+
+        #[inline(always)]
+        unsafe fn store(self, out: &mut [f64]) {
+            _mm_storeu_pd(out[..2].as_mut_ptr(), self.0)
+        }
+    }
+}
+
+/// A single lane, for targets without vector instructions to use, which measures one point at a
+/// time, just like [simplify](crate::simplify) does.
+#[cfg(not(target_arch = "x86_64"))]
+#[derive(Clone, Copy)]
+struct Single<T>(T);
+
+#[cfg(not(target_arch = "x86_64"))]
+impl<T: SimdFloat> Lanes for Single<T> {
+    type Scalar = T;
+    type Mask = bool;
+    const LANES: usize = 1;
+
+    #[inline(always)]
+    unsafe fn splat(value: T) -> Self {
+        Single(value)
+    }
+
+    #[inline(always)]
+    unsafe fn load(points: &[Point<2, T>]) -> (Self, Self) {
+        (Single(points[0].vec[0]), Single(points[0].vec[1]))
+    }
+
+    #[inline(always)]
+    unsafe fn add(self, other: Self) -> Self {
+        Single(self.0 + other.0)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(self, other: Self) -> Self {
+        Single(self.0 - other.0)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(self, other: Self) -> Self {
+        Single(self.0 * other.0)
+    }
+
+    #[inline(always)]
+    unsafe fn div(self, other: Self) -> Self {
+        Single(self.0 / other.0)
+    }
+
+    #[inline(always)]
+    unsafe fn gt(self, other: Self) -> bool {
+        self.0 > other.0
+    }
+
+    #[inline(always)]
+    unsafe fn select(mask: bool, a: Self, b: Self) -> Self {
+        if mask {
+            a
+        } else {
+            b
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn bits(mask: bool) -> u32 {
+        mask as u32
+    }
+
+    #[inline(always)]
+    unsafe fn store(self, out: &mut [T]) {
+        out[0] = self.0;
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+type F32Lanes = sse2::F32x4;
+#[cfg(target_arch = "x86_64")]
+type F64Lanes = sse2::F64x2;
+#[cfg(not(target_arch = "x86_64"))]
+type F32Lanes = Single<f32>;
+#[cfg(not(target_arch = "x86_64"))]
+type F64Lanes = Single<f64>;
+
+/// Finds the point in `first + 1..last` furthest from the segment between `first` and `last`,
+/// exactly as [max_sq_seg_dist] would, measuring as many points at a time as `L` has lanes.
+///
+/// # Safety
+///
+/// The target must support the instructions `L` uses.
+unsafe fn lanes_max_sq_seg_dist<L: Lanes>(
+    points: &[Point<2, L::Scalar>],
+    first: usize,
+    last: usize,
+    tolerance: L::Scalar,
+) -> (usize, L::Scalar) {
+    let (start, end) = (points[first], points[last]);
+    let difference = end - start;
+    if difference.is_origin() {
+        let (max_index, max_sq_dist) = max_sq_seg_dist(points, first, last, tolerance);
+        return (max_index, max_sq_dist.value());
+    }
+
+    // Every lane takes the same steps as `sq_seg_dist`, in the same order, so the distances are
+    // bit-for-bit identical to the ones it computes.
+    let (sx, sy) = (L::splat(start.vec[0]), L::splat(start.vec[1]));
+    let (ex, ey) = (L::splat(end.vec[0]), L::splat(end.vec[1]));
+    let (dx, dy) = (L::splat(difference.vec[0]), L::splat(difference.vec[1]));
+    let sq_len = L::splat(difference.sq_dist_origin());
+    let (zero, one) = (L::splat(Zero::zero()), L::splat(One::one()));
+
+    // Each lane keeps the first of its points that is furthest, as the scalar scan would.
+    let mut lane_max = L::splat(tolerance);
+    let mut lane_index = [0; MAX_LANES];
+    let chunks = points[first + 1..last].chunks_exact(L::LANES);
+    let rest = first + 1 + chunks.len() * L::LANES;
+    for (chunk, chunk_points) in chunks.enumerate() {
+        let (px, py) = L::load(chunk_points);
+        let t = px.sub(sx).mul(dx).add(py.sub(sy).mul(dy)).div(sq_len);
+        let (past_end, along) = (t.gt(one), t.gt(zero));
+        let ix = L::select(past_end, ex, L::select(along, sx.add(dx.mul(t)), sx));
+        let iy = L::select(past_end, ey, L::select(along, sy.add(dy.mul(t)), sy));
+        let (ax, ay) = (px.sub(ix), py.sub(iy));
+        let sq_dist = ax.mul(ax).add(ay.mul(ay));
+
+        let further = sq_dist.gt(lane_max);
+        let bits = L::bits(further);
+        if bits != 0 {
+            lane_max = L::select(further, sq_dist, lane_max);
+            for (lane, index) in lane_index.iter_mut().enumerate().take(L::LANES) {
+                if bits & (1 << lane) != 0 {
+                    *index = first + 1 + chunk * L::LANES + lane;
+                }
+            }
+        }
+    }
+
+    // Ties between lanes go to the lower index, which is the point the sequential scan would have
+    // found first.
+    let mut lane_maxes = [tolerance; MAX_LANES];
+    lane_max.store(&mut lane_maxes);
+    let mut max_sq_dist = tolerance;
+    let mut max_index = 0;
+    for lane in 0..L::LANES {
+        if lane_maxes[lane] > max_sq_dist
+            || (lane_maxes[lane] == max_sq_dist && lane_index[lane] < max_index)
+        {
+            max_sq_dist = lane_maxes[lane];
+            max_index = lane_index[lane];
+        }
+    }
+
+    for (i, point) in points.iter().enumerate().take(last).skip(rest) {
+        let sq_dist = get_sq_seg_dist(point, &start, &end);
+        if sq_dist > max_sq_dist {
+            max_index = i;
+            max_sq_dist = sq_dist;
+        }
+    }
+
+    (max_index, max_sq_dist)
+}
+
+impl SimdFloat for f32 {
+    #[inline]
+    fn max_sq_seg_dist(
+        points: &[Point<2, f32>],
+        first: usize,
+        last: usize,
+        tolerance: f32,
+    ) -> (usize, f32) {
+        // SAFETY: the lanes chosen for each target only use instructions it always has.
+        unsafe { lanes_max_sq_seg_dist::<F32Lanes>(points, first, last, tolerance) }
+    }
+}
+
+impl SimdFloat for f64 {
+    #[inline]
+    fn max_sq_seg_dist(
+        points: &[Point<2, f64>],
+        first: usize,
+        last: usize,
+        tolerance: f64,
+    ) -> (usize, f64) {
+        // SAFETY: the lanes chosen for each target only use instructions it always has.
+        unsafe { lanes_max_sq_seg_dist::<F64Lanes>(points, first, last, tolerance) }
+    }
+}
+
+fn simplify_dp_step_simd<T: SimdFloat>(
+    points: &[Point<2, T>],
+    first: usize,
+    last: usize,
+    tolerance: T,
+    simplified: &mut Vec<Point<2, T>>,
+) {
+    let (max_index, max_sq_dist) = T::max_sq_seg_dist(points, first, last, tolerance);
+
+    if max_sq_dist > tolerance {
+        if (max_index - first) > 1 {
+            simplify_dp_step_simd(points, first, max_index, tolerance, simplified);
+        }
+        simplified.push(points[max_index]);
+        if (last - max_index) > 1 {
+            simplify_dp_step_simd(points, max_index, last, tolerance, simplified);
+        }
+    }
+}
+
+fn simplify_douglas_peucker_simd<T: SimdFloat>(
+    points: &[Point<2, T>],
+    tolerance: T,
+) -> Vec<Point<2, T>> {
+    let mut simplified = vec![points[0]];
+    simplify_dp_step_simd(points, 0, points.len() - 1, tolerance, &mut simplified);
+    simplified.push(points[points.len() - 1]);
+
+    simplified
+}
+
+/// Simplifies a 2D floating point polyline within a given tolerance, using a vectorized
+/// Douglas–Peucker scan.
+///
+/// This produces exactly the same points as [simplify](crate::simplify). On x86_64, the distances
+/// from the points to each segment are computed with SSE2, four at a time for `f32`, or two for
+/// `f64`, loading the points straight from `points`, and measuring whatever is left over one at a
+/// time. On other targets, every point is measured one at a time, as with
+/// [simplify](crate::simplify).
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let points = points![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (4.0, 4.0)];
+/// assert_eq!(simplify_simd(&points, 1.0, true), simplify(&points, 1.0, true));
+/// ```
+pub fn simplify_simd<T: SimdFloat>(
+    points: &[Point<2, T>],
+    tolerance: T,
+    high_quality: bool,
+) -> Vec<Point<2, T>> {
+    if points.len() <= 2 {
+        return points.to_vec();
+    }
+
+    let tolerance_sq = tolerance * tolerance;
+    if high_quality {
+        simplify_douglas_peucker_simd(points, tolerance_sq)
+    } else {
+        simplify_douglas_peucker_simd(&simplify_radial_dist(points, tolerance_sq), tolerance_sq)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::SimdFloat;
+    use crate::{points, simplify, simplify_simd, Point};
+
+    fn to_f32(points: &[Point<2, f64>]) -> Vec<Point<2, f32>> {
+        points
+            .iter()
+            .map(|p| Point {
+                vec: [p.vec[0] as f32, p.vec[1] as f32],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn furthest_point() {
+        // Along (0, 0) to (10, 0), points 1 and 9 are past the ends, and 4 and 6 are equally far.
+        let points: [Point<2, f64>; 13] = points![
+            (0.0, 0.0),
+            (-2.0, 1.0),
+            (1.0, 2.0),
+            (2.0, -1.0),
+            (3.0, 3.0),
+            (4.0, 1.0),
+            (5.0, -3.0),
+            (6.0, 2.0),
+            (7.0, 0.0),
+            (13.0, 4.0),
+            (9.0, -1.0),
+            (10.0, 0.5),
+            (10.0, 0.0)
+        ];
+        assert_eq!(f64::max_sq_seg_dist(&points, 0, 12, 0.5), (9, 25.0));
+        assert_eq!(f64::max_sq_seg_dist(&points, 0, 8, 0.5), (4, 9.0));
+        assert_eq!(f64::max_sq_seg_dist(&points, 0, 12, 25.0), (0, 25.0));
+
+        let points = to_f32(&points);
+        assert_eq!(f32::max_sq_seg_dist(&points, 0, 12, 0.5), (9, 25.0));
+        assert_eq!(f32::max_sq_seg_dist(&points, 0, 8, 0.5), (4, 9.0));
+        assert_eq!(f32::max_sq_seg_dist(&points, 0, 12, 25.0), (0, 25.0));
+    }
+
+    #[test]
+    fn ties_go_to_the_first_point() {
+        // Every point but the first is 1 from the segment, so the lane holding point 2 has to win
+        // over lanes that found a later point first.
+        let points: [Point<2, f64>; 11] = points![
+            (0.0, 0.0),
+            (1.0, 0.5),
+            (2.0, -1.0),
+            (3.0, 1.0),
+            (4.0, -1.0),
+            (5.0, 1.0),
+            (6.0, -1.0),
+            (7.0, 1.0),
+            (8.0, -1.0),
+            (9.0, 1.0),
+            (10.0, 0.0)
+        ];
+        assert_eq!(f64::max_sq_seg_dist(&points, 0, 10, 0.5), (2, 1.0));
+        assert_eq!(f32::max_sq_seg_dist(&to_f32(&points), 0, 10, 0.5), (2, 1.0));
+    }
+
+    #[test]
+    fn matches_simplify() {
+        let points: [Point<2, f64>; 17] = points![
+            (0.0, 0.0),
+            (0.7, 0.3),
+            (1.4, 1.1),
+            (2.2, 0.6),
+            (2.9, 2.4),
+            (3.3, 1.9),
+            (4.1, 2.2),
+            (4.8, 0.4),
+            (5.5, -0.7),
+            (6.1, -0.2),
+            (6.6, 1.3),
+            (7.4, 1.0),
+            (8.0, 2.8),
+            (8.9, 2.5),
+            (9.3, 0.9),
+            (10.2, 1.2),
+            (11.0, 0.0)
+        ];
+        let points_f32 = to_f32(&points);
+        for high_quality in [true, false] {
+            for tolerance in [0.05, 0.5, 1.5] {
+                assert_eq!(
+                    simplify_simd(&points, tolerance, high_quality),
+                    simplify(&points, tolerance, high_quality)
+                );
+                assert_eq!(
+                    simplify_simd(&points_f32, tolerance as f32, high_quality),
+                    simplify(&points_f32, tolerance as f32, high_quality)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_segment() {
+        let points: [Point<2, f64>; 4] = points![(0.0, 0.0), (3.0, 4.0), (1.0, 1.0), (0.0, 0.0)];
+        assert_eq!(
+            simplify_simd(&points, 1.0, true),
+            simplify(&points, 1.0, true)
+        );
+    }
+}
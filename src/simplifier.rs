@@ -0,0 +1,695 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::{
+    optimal::optimal_indices,
+    report::{max_sq_dropped_dist, SimplifyReport},
+    simplify_douglas_peucker, simplify_radial_dist,
+    source::{simplify_douglas_peucker_indices_with_stats, simplify_radial_dist_indices},
+    sq_line_dist, sq_seg_dist,
+    strip::{lang_indices, opheim_indices, reumann_witkam_indices},
+    ExtendedNumOps, Point, SqDist,
+};
+
+/// The algorithms a [Simplifier] can use.
+///
+/// [simplify_hull_tree](crate::simplify_hull_tree) and [simplify_sleeve](crate::simplify_sleeve)
+/// aren't among them, as they only simplify 2D polylines of `f32` or `f64`, while a [Simplifier]
+/// works with any number of dimensions and any coordinate type. The hull tree keeps exactly the
+/// points [Algorithm::DouglasPeucker] does, so only changes how long simplifying takes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum Algorithm<T> {
+    /// Douglas–Peucker, as used by [simplify](crate::simplify). This is the default, and the only
+    /// algorithm that respects [Simplifier::min_points] and [Simplifier::max_points].
+    DouglasPeucker,
+    /// Imai–Iri, as used by [simplify_optimal](crate::simplify_optimal).
+    Optimal,
+    /// Reumann–Witkam, as used by [simplify_reumann_witkam](crate::simplify_reumann_witkam).
+    ReumannWitkam,
+    /// Opheim, as used by [simplify_opheim](crate::simplify_opheim).
+    Opheim {
+        /// How far from the last kept point each strip extends.
+        max_distance: T,
+    },
+    /// Lang, as used by [simplify_lang](crate::simplify_lang).
+    Lang {
+        /// How many points ahead of the last kept point to look, which must be at least 1.
+        look_ahead: usize,
+    },
+}
+
+/// How a [Simplifier] measures the distance from a point to the polyline that replaces it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum Metric {
+    /// The distance to the nearest point of the segment that replaces it, as used by
+    /// [simplify](crate::simplify). This is the default.
+    Segment,
+    /// The distance to the infinite line through the segment that replaces it, as in Douglas and
+    /// Peucker's original description. Points past the ends of the segment count as closer than
+    /// they are, so this keeps fewer points where the polyline doubles back on itself.
+    Line,
+}
+
+impl Default for Metric {
+    fn default() -> Self {
+        Metric::Segment
+    }
+}
+
+impl Metric {
+    #[inline(always)]
+    pub(crate) fn sq_dist<const D: usize, T: ExtendedNumOps>(
+        self,
+        pt: &Point<D, T>,
+        start: &Point<D, T>,
+        end: &Point<D, T>,
+    ) -> SqDist<T> {
+        match self {
+            Metric::Segment => sq_seg_dist(pt, start, end),
+            Metric::Line => sq_line_dist(pt, start, end),
+        }
+    }
+}
+
+/// Options for simplifying polylines, for when [simplify](crate::simplify) isn't flexible enough.
+///
+/// Start from [Simplifier::new], which behaves like `simplify(points, tolerance, true)`, change
+/// whichever options you need, then call [Simplifier::simplify] as many times as you like.
+///
+/// There is no option to preserve the endpoints, as every algorithm always keeps the first and
+/// last points, and a polyline that didn't would no longer start and end where it did.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let points = points![(0.0, 0.0), (1.0, 0.1), (2.0, -0.1), (3.0, 5.0), (4.0, 6.0), (5.0, 7.0)];
+///
+/// let simplifier = Simplifier::new(1.0);
+/// assert_eq!(simplifier.simplify(&points), simplify(&points, 1.0, true));
+/// assert_eq!(
+///     simplifier.simplify(&points),
+///     points![(0.0, 0.0), (2.0, -0.1), (3.0, 5.0), (5.0, 7.0)]
+/// );
+///
+/// // Keep the most important points, but no more than 3, however far the rest are.
+/// assert_eq!(
+///     simplifier.max_points(3).simplify(&points),
+///     points![(0.0, 0.0), (2.0, -0.1), (5.0, 7.0)]
+/// );
+///
+/// // Drop points within 0.2 of the last one kept, then simplify what's left more coarsely.
+/// assert_eq!(
+///     simplifier.radial_tolerance(0.2).tolerance(1.5).simplify(&points),
+///     points![(0.0, 0.0), (2.0, -0.1), (5.0, 7.0)]
+/// );
+///
+/// // Use a different algorithm, after dropping points close to the last one kept.
+/// assert_eq!(
+///     simplifier
+///         .algorithm(Algorithm::ReumannWitkam)
+///         .radial_pass(true)
+///         .simplify(&points),
+///     points![(0.0, 0.0), (2.0, -0.1), (4.0, 6.0), (5.0, 7.0)]
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Simplifier<const D: usize, T> {
+    tolerance: T,
+    algorithm: Algorithm<T>,
+    metric: Metric,
+    radial_pass: bool,
+    radial_tolerance: Option<T>,
+    min_points: usize,
+    max_points: Option<usize>,
+    weights: Option<[T; D]>,
+}
+
+impl<const D: usize, T: ExtendedNumOps> Simplifier<D, T> {
+    /// A simplifier that uses Douglas–Peucker with the given tolerance, like
+    /// `simplify(points, tolerance, true)`.
+    pub fn new(tolerance: T) -> Self {
+        Simplifier {
+            tolerance,
+            algorithm: Algorithm::DouglasPeucker,
+            metric: Metric::Segment,
+            radial_pass: false,
+            radial_tolerance: None,
+            min_points: 0,
+            max_points: None,
+            weights: None,
+        }
+    }
+
+    /// Sets the distance dropped points may be from the simplified polyline.
+    pub fn tolerance(mut self, tolerance: T) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets the algorithm used to simplify. Defaults to [Algorithm::DouglasPeucker].
+    pub fn algorithm(mut self, algorithm: Algorithm<T>) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Sets how the distance from each point to the simplified polyline is measured. Defaults to
+    /// [Metric::Segment].
+    ///
+    /// Only [Algorithm::DouglasPeucker] respects this.
+    pub fn metric(mut self, metric: Metric) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    /// Sets whether points within the tolerance of the last kept point are dropped before the
+    /// algorithm runs, which is faster, but lower quality. This is what `high_quality: false`
+    /// does for [simplify](crate::simplify). Defaults to off, but [Simplifier::radial_tolerance]
+    /// turns it on. Turning it off again keeps the radial tolerance for if it is turned back on.
+    pub fn radial_pass(mut self, radial_pass: bool) -> Self {
+        self.radial_pass = radial_pass;
+        self
+    }
+
+    /// Turns on the radial distance pass, as [Simplifier::radial_pass] does, with its own
+    /// tolerance rather than the one the algorithm uses. A small radial tolerance drops noise,
+    /// like GPS jitter, cheaply, while a larger one for the algorithm generalizes the shape.
+    /// Defaults to the same tolerance as the algorithm.
+    pub fn radial_tolerance(mut self, radial_tolerance: T) -> Self {
+        self.radial_pass = true;
+        self.radial_tolerance = Some(radial_tolerance);
+        self
+    }
+
+    /// Keeps splitting the polyline at its furthest point, even once every point is within the
+    /// tolerance, until at least this many points are kept, or none are left. The first and last
+    /// points are always kept, so 2 or less has no effect. Defaults to 0.
+    ///
+    /// Only [Algorithm::DouglasPeucker] respects this.
+    pub fn min_points(mut self, min_points: usize) -> Self {
+        self.min_points = min_points;
+        self
+    }
+
+    /// Stops splitting the polyline once this many points are kept, even if some of the rest are
+    /// outside the tolerance. The points kept are the ones furthest from the polyline so far, so
+    /// they are the ones Douglas–Peucker would have kept first. The first and last points are
+    /// always kept, so anything less than 2 is treated as 2. Defaults to no limit.
+    ///
+    /// Only [Algorithm::DouglasPeucker] respects this.
+    pub fn max_points(mut self, max_points: usize) -> Self {
+        self.max_points = Some(max_points);
+        self
+    }
+
+    /// Scales each axis by its own weight before measuring distances, as
+    /// [simplify_weighted](crate::simplify_weighted) does. Defaults to no weights.
+    pub fn weights(mut self, weights: [T; D]) -> Self {
+        self.weights = Some(weights);
+        self
+    }
+
+    /// Simplifies a polyline with these options.
+    pub fn simplify(&self, points: &[Point<D, T>]) -> Vec<Point<D, T>> {
+        if points.len() <= 2 {
+            return points.to_vec();
+        }
+
+        if self.weights.is_none() && self.metric == Metric::Segment && !self.has_point_limits() {
+            if let Algorithm::DouglasPeucker = self.algorithm {
+                let tolerance_sq = self.tolerance * self.tolerance;
+                return if self.radial_pass {
+                    simplify_douglas_peucker(
+                        &simplify_radial_dist(points, self.radial_tolerance_sq()),
+                        tolerance_sq,
+                    )
+                } else {
+                    simplify_douglas_peucker(points, tolerance_sq)
+                };
+            }
+        }
+
+        self.kept_indices(points, None)
+            .into_iter()
+            .map(|i| points[i])
+            .collect()
+    }
+
+    /// Simplifies a polyline with these options, like [Simplifier::simplify], and also reports
+    /// how many points each pass kept, how far dropped points were from the result, and how
+    /// deeply Douglas–Peucker recursed.
+    pub fn simplify_with_report(
+        &self,
+        points: &[Point<D, T>],
+    ) -> (Vec<Point<D, T>>, SimplifyReport<T>) {
+        let mut report = SimplifyReport::unchanged(points.len());
+        if points.len() <= 2 {
+            return (points.to_vec(), report);
+        }
+
+        let simplified: Vec<Point<D, T>> = self
+            .kept_indices(points, Some(&mut report))
+            .into_iter()
+            .map(|i| points[i])
+            .collect();
+        report.output_len = simplified.len();
+
+        (simplified, report)
+    }
+
+    fn radial_tolerance_sq(&self) -> T {
+        let radial_tolerance = self.radial_tolerance.unwrap_or(self.tolerance);
+        radial_tolerance * radial_tolerance
+    }
+
+    fn has_point_limits(&self) -> bool {
+        self.min_points > 2 || self.max_points.is_some()
+    }
+
+    /// The indices of the points to keep, filling in `report`, if any, except for its output
+    /// length.
+    fn kept_indices(
+        &self,
+        points: &[Point<D, T>],
+        mut report: Option<&mut SimplifyReport<T>>,
+    ) -> Vec<usize> {
+        let weighted: Vec<Point<D, T>>;
+        let points = match self.weights {
+            Some(weights) => {
+                let weights = Point { vec: weights };
+                weighted = points.iter().map(|p| p * weights).collect();
+                &weighted[..]
+            }
+            None => points,
+        };
+
+        if !self.radial_pass {
+            return self.algorithm_indices(points, report);
+        }
+
+        let intermediate = simplify_radial_dist_indices(points, self.radial_tolerance_sq());
+        if let Some(report) = report.as_deref_mut() {
+            report.radial_len = intermediate.len();
+        }
+        let subset: Vec<Point<D, T>> = intermediate.iter().map(|&i| points[i]).collect();
+        let kept: Vec<usize> = self
+            .algorithm_indices(&subset, report.as_deref_mut())
+            .into_iter()
+            .map(|i| intermediate[i])
+            .collect();
+
+        // The algorithm only measured the points the radial pass left, so measure the ones it
+        // dropped too, against the segments of the result rather than the points they were near.
+        if let Some(report) = report {
+            report.max_sq_deviation = max_sq_dropped_dist(points, &kept, self.metric);
+        }
+        kept
+    }
+
+    fn algorithm_indices(
+        &self,
+        points: &[Point<D, T>],
+        report: Option<&mut SimplifyReport<T>>,
+    ) -> Vec<usize> {
+        if points.len() <= 2 {
+            return (0..points.len()).collect();
+        }
+
+        let tolerance = self.tolerance;
+        let mut depth = 0;
+        let kept = match self.algorithm {
+            Algorithm::DouglasPeucker if self.has_point_limits() => {
+                limited_douglas_peucker_indices(
+                    points,
+                    tolerance * tolerance,
+                    self.min_points,
+                    self.max_points.unwrap_or(usize::MAX).max(2),
+                    self.metric,
+                    &mut depth,
+                )
+            }
+            Algorithm::DouglasPeucker => {
+                let (kept, stats) = simplify_douglas_peucker_indices_with_stats(
+                    points,
+                    tolerance * tolerance,
+                    self.metric,
+                );
+                if let Some(report) = report {
+                    report.depth = stats.depth;
+                    report.max_sq_deviation = stats.max_sq_dist.value();
+                }
+                return kept;
+            }
+            Algorithm::Optimal => optimal_indices(points, tolerance),
+            Algorithm::ReumannWitkam => reumann_witkam_indices(points, tolerance),
+            Algorithm::Opheim { max_distance } => opheim_indices(points, tolerance, max_distance),
+            Algorithm::Lang { look_ahead } => lang_indices(points, tolerance, look_ahead),
+        };
+
+        // Only plain Douglas–Peucker measures the points it drops as it goes, so measure the
+        // others' once they are done.
+        if let Some(report) = report {
+            report.depth = depth;
+            report.max_sq_deviation = max_sq_dropped_dist(points, &kept, self.metric);
+        }
+        kept
+    }
+}
+
+/// A range of points still to be split, ordered by how far its furthest point is from the
+/// segment between its ends, then by position, so ties split the earliest range first. For
+/// integer types, the furthest point of each range is found exactly, but ranges are ordered by
+/// its distance rounded up.
+struct Range<T> {
+    sq_dist: SqDist<T>,
+    first: usize,
+    last: usize,
+    furthest: usize,
+    /// How many splits deep this range is, counting the whole polyline as 1.
+    depth: usize,
+}
+
+impl<T: ExtendedNumOps> Range<T> {
+    fn new<const D: usize>(
+        points: &[Point<D, T>],
+        first: usize,
+        last: usize,
+        metric: Metric,
+        depth: usize,
+    ) -> Self {
+        let mut max_sq_dist = metric.sq_dist(&points[first + 1], &points[first], &points[last]);
+        let mut furthest = first + 1;
+        for i in first + 2..last {
+            let sq_dist = metric.sq_dist(&points[i], &points[first], &points[last]);
+            if sq_dist > max_sq_dist {
+                max_sq_dist = sq_dist;
+                furthest = i;
+            }
+        }
+
+        Range {
+            sq_dist: max_sq_dist.rounded_up(),
+            first,
+            last,
+            furthest,
+            depth,
+        }
+    }
+}
+
+impl<T: PartialOrd> PartialEq for Range<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd> Eq for Range<T> {}
+
+impl<T: PartialOrd> PartialOrd for Range<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> Ord for Range<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sq_dist
+            .partial_cmp(&other.sq_dist)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.first.cmp(&self.first))
+    }
+}
+
+/// Douglas–Peucker, splitting the range with the furthest point first, so that it can stop at
+/// `max_points`, or carry on past the tolerance until `min_points`. Sets `depth` to how deeply
+/// the polyline was split, as [SimplifyReport::depth] counts it.
+fn limited_douglas_peucker_indices<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    tolerance: T,
+    min_points: usize,
+    max_points: usize,
+    metric: Metric,
+    depth: &mut usize,
+) -> Vec<usize> {
+    let last = points.len() - 1;
+    let mut kept = vec![false; points.len()];
+    kept[0] = true;
+    kept[last] = true;
+    let mut count = 2;
+
+    let mut ranges = BinaryHeap::new();
+    ranges.push(Range::new(points, 0, last, metric, 1));
+    while let Some(range) = ranges.pop() {
+        if count >= max_points || (range.sq_dist <= SqDist::new(tolerance) && count >= min_points) {
+            break;
+        }
+
+        kept[range.furthest] = true;
+        count += 1;
+        *depth = (*depth).max(range.depth);
+        let next_depth = range.depth + 1;
+        if range.furthest - range.first > 1 {
+            ranges.push(Range::new(
+                points,
+                range.first,
+                range.furthest,
+                metric,
+                next_depth,
+            ));
+        }
+        if range.last - range.furthest > 1 {
+            ranges.push(Range::new(
+                points,
+                range.furthest,
+                range.last,
+                metric,
+                next_depth,
+            ));
+        }
+    }
+
+    (0..points.len()).filter(|&i| kept[i]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{Algorithm, Metric, Simplifier};
+    use crate::{
+        simplify, simplify_lang, simplify_opheim, simplify_optimal, simplify_radial_dist,
+        simplify_reumann_witkam, simplify_weighted, Point,
+    };
+
+    /// A GPS track with a little jitter, which runs flat, climbs, then comes back down more
+    /// gently, with its points closer together than the jitter in places.
+    fn track() -> Vec<Point<2, f64>> {
+        (0..60)
+            .map(|i| {
+                let y = match i {
+                    0..=19 => 0.0,
+                    20..=39 => (i - 19) as f64 * 0.5,
+                    _ => 10.0 - (i - 39) as f64 * 0.25,
+                };
+                Point {
+                    vec: [i as f64 * 0.25, y + ((i * 7) % 5) as f64 * 0.04],
+                }
+            })
+            .collect()
+    }
+
+    fn kept(points: &[Point<2, f64>], indices: &[usize]) -> Vec<Point<2, f64>> {
+        indices.iter().map(|&i| points[i]).collect()
+    }
+
+    #[test]
+    fn defaults_match_simplify() {
+        let points = track();
+        let simplifier = Simplifier::new(0.5);
+        assert_eq!(
+            simplifier.simplify(&points),
+            kept(&points, &[0, 19, 39, 59])
+        );
+        assert_eq!(
+            simplifier.radial_pass(true).simplify(&points),
+            kept(&points, &[0, 20, 39, 59])
+        );
+
+        for tolerance in [0.05, 0.5, 2.0] {
+            let simplifier = Simplifier::new(tolerance);
+            assert_eq!(
+                simplifier.simplify(&points),
+                simplify(&points, tolerance, true)
+            );
+            assert_eq!(
+                simplifier.radial_pass(true).simplify(&points),
+                simplify(&points, tolerance, false)
+            );
+            // Without limits, splitting the furthest range first keeps the same points.
+            assert_eq!(
+                simplifier.max_points(points.len()).simplify(&points),
+                simplify(&points, tolerance, true)
+            );
+        }
+    }
+
+    #[test]
+    fn algorithms_match_their_functions() {
+        let points = track();
+        let simplifier = Simplifier::new(0.5);
+        assert_eq!(
+            simplifier.algorithm(Algorithm::Optimal).simplify(&points),
+            simplify_optimal(&points, 0.5)
+        );
+        assert_eq!(
+            simplifier
+                .algorithm(Algorithm::ReumannWitkam)
+                .simplify(&points),
+            simplify_reumann_witkam(&points, 0.5)
+        );
+        assert_eq!(
+            simplifier
+                .algorithm(Algorithm::Opheim { max_distance: 3.0 })
+                .simplify(&points),
+            simplify_opheim(&points, 0.5, 3.0)
+        );
+        assert_eq!(
+            simplifier
+                .algorithm(Algorithm::Lang { look_ahead: 8 })
+                .simplify(&points),
+            simplify_lang(&points, 0.5, 8)
+        );
+        for high_quality in [true, false] {
+            assert_eq!(
+                simplifier
+                    .weights([0.5, 2.0])
+                    .radial_pass(!high_quality)
+                    .simplify(&points),
+                simplify_weighted(&points, [0.5, 2.0], 0.5, high_quality)
+            );
+        }
+    }
+
+    #[test]
+    fn separate_radial_tolerance() {
+        let points = track();
+        assert_eq!(
+            Simplifier::new(0.5).radial_tolerance(0.5).simplify(&points),
+            simplify(&points, 0.5, false)
+        );
+
+        let filtered = simplify_radial_dist(&points, 0.1 * 0.1);
+        let coarse = Simplifier::new(1.0).radial_tolerance(0.1);
+        assert_eq!(coarse.simplify(&points), simplify(&filtered, 1.0, true));
+        assert_eq!(
+            coarse.weights([1.0, 1.0]).simplify(&points),
+            coarse.simplify(&points)
+        );
+        assert_ne!(coarse.simplify(&points), simplify(&points, 1.0, false));
+
+        // Turning the radial pass off keeps its tolerance for when it is turned back on.
+        assert_eq!(
+            coarse.radial_pass(false).simplify(&points),
+            simplify(&points, 1.0, true)
+        );
+        assert_eq!(coarse.radial_pass(false).radial_pass(true), coarse);
+    }
+
+    #[test]
+    fn point_limits() {
+        let points = track();
+        assert_eq!(
+            Simplifier::new(0.5).max_points(3).simplify(&points),
+            kept(&points, &[0, 39, 59])
+        );
+
+        let unlimited = simplify(&points, 0.1, true);
+        for max_points in [0, 2, 5, unlimited.len() - 1] {
+            let limited = Simplifier::new(0.1)
+                .max_points(max_points)
+                .simplify(&points);
+            assert_eq!(limited.len(), max_points.max(2));
+            assert!(limited.iter().all(|p| unlimited.contains(p)));
+        }
+
+        let at_least = Simplifier::new(0.1)
+            .min_points(unlimited.len() + 10)
+            .simplify(&points);
+        // Splitting at a point within the tolerance can leave another outside it, so this keeps
+        // one more point than asked for.
+        assert_eq!(at_least.len(), unlimited.len() + 11);
+        assert!(unlimited.iter().all(|p| at_least.contains(p)));
+        for min_points in [0, 1, 2] {
+            assert_eq!(
+                Simplifier::new(0.1)
+                    .min_points(min_points)
+                    .simplify(&points),
+                unlimited
+            );
+        }
+
+        let straight: Vec<Point<2, f64>> = (0..5)
+            .map(|i| Point {
+                vec: [i as f64, 0.0],
+            })
+            .collect();
+        assert_eq!(
+            Simplifier::new(1.0).min_points(100).simplify(&straight),
+            straight
+        );
+    }
+
+    #[test]
+    fn line_metric() {
+        let points = [
+            Point { vec: [0.0, 0.0] },
+            Point { vec: [4.0, 0.5] },
+            Point { vec: [12.0, 0.0] },
+            Point { vec: [10.0, 0.0] },
+        ];
+        assert_eq!(
+            Simplifier::new(1.0).simplify(&points),
+            [points[0], points[2], points[3]]
+        );
+        let line = Simplifier::new(1.0).metric(Metric::Line);
+        assert_eq!(line.simplify(&points), [points[0], points[3]]);
+        assert_eq!(line.max_points(4).simplify(&points), line.simplify(&points));
+        assert_eq!(line.simplify_with_report(&points).1.max_sq_deviation, 0.25);
+
+        let points = track();
+        let line = Simplifier::new(0.5).metric(Metric::Line);
+        assert_eq!(
+            line.max_points(points.len()).simplify(&points),
+            line.simplify(&points)
+        );
+    }
+
+    #[test]
+    fn keeps_both_ends() {
+        let points: Vec<Point<2, f64>> = (0..10)
+            .map(|i| Point {
+                vec: [0.0, i as f64],
+            })
+            .collect();
+        assert_eq!(
+            Simplifier::new(0.5)
+                .weights([1.0, 0.0])
+                .radial_pass(true)
+                .simplify(&points),
+            [points[0], points[9]]
+        );
+
+        let same = [Point { vec: [1.0, 1.0] }; 5];
+        let simplified = simplify(&same, 0.5, false);
+        assert_eq!(simplified.len(), 2);
+        for simplifier in [
+            Simplifier::new(0.5).radial_pass(true).max_points(10),
+            Simplifier::new(0.5).radial_pass(true).min_points(10),
+            Simplifier::new(0.5).weights([1.0, 1.0]).radial_pass(true),
+        ] {
+            assert_eq!(simplifier.simplify(&same), simplified);
+        }
+    }
+}
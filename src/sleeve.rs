@@ -0,0 +1,219 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::Float;
+
+use crate::{ExtendedNumOps, Point};
+
+#[inline(always)]
+fn cross<T: ExtendedNumOps>(a: &Point<2, T>, b: &Point<2, T>) -> T {
+    a.vec[0] * b.vec[1] - a.vec[1] * b.vec[0]
+}
+
+/// The directions from an anchor along which a segment stays within tolerance of every point
+/// since it, bounded by its most clockwise and most counterclockwise directions.
+struct Sleeve<T: ExtendedNumOps> {
+    right: Point<2, T>,
+    left: Point<2, T>,
+}
+
+impl<T: ExtendedNumOps + Float> Sleeve<T> {
+    /// The directions from the anchor that pass within `tolerance` of a point `offset` from it,
+    /// which must be further away than `tolerance`.
+    fn around(offset: Point<2, T>, sq_dist: T, tolerance: T) -> Self {
+        // Rotate the offset either way by the angle whose sine is `tolerance / distance`.
+        let cos = (sq_dist - tolerance * tolerance).sqrt();
+        let sin = tolerance;
+        let [x, y] = offset.vec;
+        Sleeve {
+            right: Point {
+                vec: [x * cos + y * sin, y * cos - x * sin],
+            },
+            left: Point {
+                vec: [x * cos - y * sin, y * cos + x * sin],
+            },
+        }
+    }
+
+    fn contains(&self, direction: &Point<2, T>) -> bool {
+        cross(&self.right, direction) >= T::zero() && cross(direction, &self.left) >= T::zero()
+    }
+
+    fn narrow(&mut self, other: Sleeve<T>) {
+        if cross(&self.right, &other.right) > T::zero() {
+            self.right = other.right;
+        }
+        if cross(&other.left, &self.left) > T::zero() {
+            self.left = other.left;
+        }
+    }
+}
+
+/// Simplifies a 2D polyline within a given tolerance using Zhao and Saalfeld's sleeve-fitting
+/// algorithm, in a single forward pass.
+///
+/// From the last kept point, a sleeve of the directions along which a segment would stay within
+/// `tolerance` of every point since is narrowed one point at a time. When the next point falls
+/// outside the sleeve, the point before it is kept, and a new sleeve starts there. Points are also
+/// kept before the polyline doubles back towards the last kept point by more than half of
+/// `tolerance`, as a segment can only stay within `tolerance` of points it reaches, or nearly
+/// reaches. Points jittering back and forth by less than that are dropped.
+///
+/// Every dropped point is within `tolerance` of the segment between the kept points either side of
+/// it, so the simplified polyline is within `tolerance` of the original, in Hausdorff distance.
+/// Unlike Douglas–Peucker, which needs the whole polyline up front, this only ever looks at one
+/// point at a time, and runs in linear time, so it suits polylines too large to simplify in one
+/// go, or that are still being recorded. It generally keeps somewhat more points than
+/// Douglas–Peucker does.
+///
+/// Only available with the `std` feature.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let points = points![(0.0, 0.0), (1.0, 0.2), (2.0, -0.2), (3.0, 0.0), (3.0, 3.0)];
+///
+/// assert_eq!(
+///     simplify_sleeve(&points, 0.5),
+///     points![(0.0, 0.0), (3.0, 0.0), (3.0, 3.0)]
+/// );
+/// ```
+pub fn simplify_sleeve<T: ExtendedNumOps + Float>(
+    points: &[Point<2, T>],
+    tolerance: T,
+) -> Vec<Point<2, T>> {
+    if points.len() <= 2 {
+        return points.to_vec();
+    }
+
+    // A segment from the anchor can end short of points it has passed, as long as it still ends
+    // within `tolerance` of them. Fitting the sleeve to a narrower tolerance leaves room for it to
+    // end up to `slack` short of the furthest point, while staying within `tolerance` of it, so
+    // points that jitter back and forth along a line don't each start a new segment.
+    let slack = tolerance / (T::one() + T::one());
+    let sleeve_tolerance = (tolerance * tolerance - slack * slack).sqrt();
+    let tolerance_sq = tolerance * tolerance;
+    let mut kept = vec![points[0]];
+    let mut anchor = points[0];
+    let mut sleeve: Option<Sleeve<T>> = None;
+    let mut max_dist = T::zero();
+    let mut i = 1;
+
+    while i < points.len() {
+        let offset = points[i] - anchor;
+        let sq_dist = offset.sq_dist_origin();
+        let dist = sq_dist.sqrt();
+        let in_sleeve = match &sleeve {
+            Some(sleeve) => sleeve.contains(&offset),
+            None => true,
+        };
+        let doubles_back = max_dist > tolerance && dist < max_dist - slack;
+
+        if !in_sleeve || doubles_back {
+            // The previous point is the last one a segment from the anchor can end at, and is
+            // never the anchor itself, as any point straight after the anchor is accepted.
+            anchor = points[i - 1];
+            kept.push(anchor);
+            sleeve = None;
+            max_dist = T::zero();
+            continue;
+        }
+
+        max_dist = max_dist.max(dist);
+        if sq_dist > tolerance_sq {
+            let around = Sleeve::around(offset, sq_dist, sleeve_tolerance);
+            match &mut sleeve {
+                Some(sleeve) => sleeve.narrow(around),
+                None => sleeve = Some(around),
+            }
+        }
+        i += 1;
+    }
+    kept.push(points[points.len() - 1]);
+
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::simplify_sleeve;
+    use crate::{metrics::max_deviation, points, simplify, Point};
+
+    #[test]
+    fn stays_within_tolerance() {
+        // A path that wavers along, turns a corner, and wavers along again.
+        let points = points![
+            (0.0, 0.0),
+            (1.0, 0.1),
+            (2.0, -0.1),
+            (3.0, 0.05),
+            (4.0, 0.0),
+            (5.0, 0.2),
+            (6.0, 1.0),
+            (7.0, 2.1),
+            (7.5, 3.0),
+            (7.6, 4.0),
+            (7.4, 5.0),
+            (7.5, 6.0),
+            (8.0, 6.6),
+            (9.0, 6.8),
+            (10.0, 6.7),
+            (11.0, 6.9)
+        ];
+        let kept = |indices: &[usize]| indices.iter().map(|&i| points[i]).collect::<Vec<_>>();
+
+        assert_eq!(
+            simplify_sleeve(&points, 0.25),
+            kept(&[0, 5, 7, 9, 11, 12, 15])
+        );
+        assert_eq!(simplify_sleeve(&points, 0.5), simplify(&points, 0.5, true));
+        assert_eq!(simplify_sleeve(&points, 1.0), kept(&[0, 6, 12, 15]));
+        for tolerance in [0.25, 0.5, 1.0, 2.0] {
+            assert!(max_deviation(&points, &simplify_sleeve(&points, tolerance)) <= tolerance);
+        }
+    }
+
+    #[test]
+    fn keeps_reversals() {
+        let points = [
+            Point { vec: [0.0, 0.0] },
+            Point { vec: [5.0, 0.0] },
+            Point { vec: [2.0, 0.0] },
+        ];
+        assert_eq!(simplify_sleeve(&points, 0.5), points);
+    }
+
+    #[test]
+    fn drops_jitter_within_tolerance() {
+        let points = [
+            Point { vec: [0.0, 0.0] },
+            Point { vec: [0.1, 0.0] },
+            Point { vec: [0.05, 0.0] },
+            Point { vec: [0.12, 0.0] },
+            Point { vec: [10.0, 0.0] },
+        ];
+        assert_eq!(simplify_sleeve(&points, 1.0), [points[0], points[4]]);
+
+        let points = [
+            Point { vec: [0.0, 0.0] },
+            Point { vec: [5.0, 0.0] },
+            Point { vec: [4.7, 0.1] },
+            Point { vec: [5.2, -0.1] },
+            Point { vec: [4.9, 0.0] },
+            Point { vec: [10.0, 0.0] },
+        ];
+        assert_eq!(simplify_sleeve(&points, 1.0), [points[0], points[5]]);
+    }
+
+    #[test]
+    fn straight_lines() {
+        let points: Vec<Point<2, f32>> = (0..100)
+            .map(|i| Point {
+                vec: [i as f32, i as f32 * 0.5],
+            })
+            .collect();
+        assert_eq!(simplify_sleeve(&points, 0.1), [points[0], points[99]]);
+    }
+}
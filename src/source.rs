@@ -0,0 +1,201 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{ExtendedNumOps, Metric, Point, SqDist, Vertex};
+
+/// Anything the simplification passes can read points out of by index, for inputs that aren't
+/// already laid out as a `&[Point<D, T>]`.
+pub(crate) trait PointSource<const D: usize, T: ExtendedNumOps> {
+    fn len(&self) -> usize;
+
+    fn point(&self, index: usize) -> Point<D, T>;
+}
+
+impl<const D: usize, T: ExtendedNumOps, V: Vertex<D, T>> PointSource<D, T> for [V] {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <[V]>::len(self)
+    }
+
+    #[inline(always)]
+    fn point(&self, index: usize) -> Point<D, T> {
+        Point {
+            vec: self[index].coords(),
+        }
+    }
+}
+
+/// The points of `source` at `indices`, in that order.
+struct Subset<'a, S: ?Sized> {
+    source: &'a S,
+    indices: &'a [usize],
+}
+
+impl<'a, const D: usize, T: ExtendedNumOps, S: PointSource<D, T> + ?Sized> PointSource<D, T>
+    for Subset<'a, S>
+{
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    #[inline(always)]
+    fn point(&self, index: usize) -> Point<D, T> {
+        self.source.point(self.indices[index])
+    }
+}
+
+pub(crate) fn simplify_radial_dist_indices<const D: usize, T: ExtendedNumOps, S>(
+    source: &S,
+    tolerance: T,
+) -> Vec<usize>
+where
+    S: PointSource<D, T> + ?Sized,
+{
+    let mut prev_point = source.point(0);
+    let mut kept = vec![0];
+
+    for i in 1..source.len() {
+        let point = source.point(i);
+        if SqDist::between(&point, &prev_point) > SqDist::new(tolerance) {
+            kept.push(i);
+            prev_point = point;
+        }
+    }
+
+    // The last point is compared by index rather than by value, as a source may map different
+    // points to the same coordinates, which would otherwise drop the real end of the polyline.
+    let last = source.len() - 1;
+    if kept[kept.len() - 1] != last {
+        kept.push(last);
+    }
+
+    kept
+}
+
+/// What Douglas–Peucker found while splitting a polyline, for
+/// [SimplifyReport](crate::SimplifyReport).
+pub(crate) struct SplitStats<T> {
+    /// How deeply the polyline was split, which is 0 if only its ends were kept.
+    pub(crate) depth: usize,
+    /// The largest squared distance from a dropped point to the segment that replaced it, rounded
+    /// up.
+    pub(crate) max_sq_dist: SqDist<T>,
+}
+
+impl<T: ExtendedNumOps> SplitStats<T> {
+    pub(crate) fn new() -> Self {
+        SplitStats {
+            depth: 0,
+            max_sq_dist: SqDist::new(T::zero()),
+        }
+    }
+}
+
+/// Douglas–Peucker over the points of a source, collecting the indices of the points it keeps.
+struct DouglasPeucker<'a, T, S: ?Sized> {
+    source: &'a S,
+    tolerance: T,
+    metric: Metric,
+    kept: Vec<usize>,
+    stats: SplitStats<T>,
+}
+
+impl<'a, T: ExtendedNumOps, S: ?Sized> DouglasPeucker<'a, T, S> {
+    fn step<const D: usize>(&mut self, first: usize, last: usize, depth: usize)
+    where
+        S: PointSource<D, T>,
+    {
+        let (start, end) = (self.source.point(first), self.source.point(last));
+        // The furthest point is found even when it is within the tolerance, for `stats`. Only the
+        // first of several points just as far is taken, so this splits where a search for points
+        // beyond the tolerance would.
+        let mut max_sq_dist = SqDist::new(T::zero());
+        let mut max_index = 0;
+
+        for i in first + 1..last {
+            let sq_dist = self.metric.sq_dist(&self.source.point(i), &start, &end);
+            if sq_dist > max_sq_dist {
+                max_index = i;
+                max_sq_dist = sq_dist;
+            }
+        }
+
+        let rounded_sq_dist = max_sq_dist.rounded_up();
+        if rounded_sq_dist > SqDist::new(self.tolerance) {
+            if depth > self.stats.depth {
+                self.stats.depth = depth;
+            }
+            if (max_index - first) > 1 {
+                self.step(first, max_index, depth + 1);
+            }
+            self.kept.push(max_index);
+            if (last - max_index) > 1 {
+                self.step(max_index, last, depth + 1);
+            }
+        } else if rounded_sq_dist > self.stats.max_sq_dist {
+            self.stats.max_sq_dist = rounded_sq_dist;
+        }
+    }
+}
+
+pub(crate) fn simplify_douglas_peucker_indices<const D: usize, T: ExtendedNumOps, S>(
+    source: &S,
+    tolerance: T,
+) -> Vec<usize>
+where
+    S: PointSource<D, T> + ?Sized,
+{
+    simplify_douglas_peucker_indices_with_stats(source, tolerance, Metric::Segment).0
+}
+
+/// Same as [simplify_douglas_peucker_indices], but measuring distances with `metric`, and also
+/// returning what it found while splitting.
+pub(crate) fn simplify_douglas_peucker_indices_with_stats<const D: usize, T: ExtendedNumOps, S>(
+    source: &S,
+    tolerance: T,
+    metric: Metric,
+) -> (Vec<usize>, SplitStats<T>)
+where
+    S: PointSource<D, T> + ?Sized,
+{
+    let mut dp = DouglasPeucker {
+        source,
+        tolerance,
+        metric,
+        kept: vec![0],
+        stats: SplitStats::new(),
+    };
+    dp.step(0, source.len() - 1, 1);
+    dp.kept.push(source.len() - 1);
+
+    (dp.kept, dp.stats)
+}
+
+/// Same as [simplify](crate::simplify), but returns the indices of the kept points in `source`.
+pub(crate) fn simplify_indices<const D: usize, T: ExtendedNumOps, S>(
+    source: &S,
+    tolerance: T,
+    high_quality: bool,
+) -> Vec<usize>
+where
+    S: PointSource<D, T> + ?Sized,
+{
+    if source.len() <= 2 {
+        return (0..source.len()).collect();
+    }
+
+    let tolerance_sq = tolerance * tolerance;
+    if high_quality {
+        simplify_douglas_peucker_indices(source, tolerance_sq)
+    } else {
+        let intermediate = simplify_radial_dist_indices(source, tolerance_sq);
+        let subset = Subset {
+            source,
+            indices: &intermediate,
+        };
+        simplify_douglas_peucker_indices(&subset, tolerance_sq)
+            .into_iter()
+            .map(|i| intermediate[i])
+            .collect()
+    }
+}
@@ -0,0 +1,300 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{sq_line_dist, sq_seg_dist, ExtendedNumOps, Point, SqDist};
+
+fn kept_points<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    kept: Vec<usize>,
+) -> Vec<Point<D, T>> {
+    kept.into_iter().map(|i| points[i]).collect()
+}
+
+/// Simplifies a polyline within a given tolerance using the Reumann–Witkam algorithm.
+///
+/// A strip `2 * tolerance` wide is laid along the line through the last kept point and the point
+/// after it. Points are dropped until one falls outside the strip, at which point the point
+/// before it is kept, and a new strip starts there.
+///
+/// This makes a single pass over the points, and only ever looks at the current one, so it is
+/// linear-time and can simplify a polyline as it is being streamed in. It keeps more points than
+/// Douglas–Peucker for the same tolerance, and makes no guarantee about how far dropped points
+/// are from the simplified polyline, only from their strip's center line.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let points = points![(0.0, 0.0), (1.0, 0.1), (2.0, 0.0), (3.0, 2.0), (4.0, 4.0)];
+///
+/// assert_eq!(
+///     simplify_reumann_witkam(&points, 0.5),
+///     points![(0.0, 0.0), (2.0, 0.0), (4.0, 4.0)]
+/// );
+/// ```
+pub fn simplify_reumann_witkam<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    tolerance: T,
+) -> Vec<Point<D, T>> {
+    kept_points(points, reumann_witkam_indices(points, tolerance))
+}
+
+/// Same as [simplify_reumann_witkam], but returns the indices of the kept points.
+pub(crate) fn reumann_witkam_indices<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    tolerance: T,
+) -> Vec<usize> {
+    if points.len() <= 2 {
+        return (0..points.len()).collect();
+    }
+
+    let tolerance_sq = SqDist::new(tolerance * tolerance);
+    let mut kept = vec![0];
+    let mut key = 0;
+    let mut direction = 1;
+
+    for i in 2..points.len() {
+        if sq_line_dist(&points[i], &points[key], &points[direction]) > tolerance_sq {
+            key = i - 1;
+            direction = i;
+            kept.push(key);
+        }
+    }
+    kept.push(points.len() - 1);
+
+    kept
+}
+
+/// Simplifies a polyline within a given tolerance using the Opheim algorithm.
+///
+/// Like [simplify_reumann_witkam], but the strip's direction is taken from the last kept point to
+/// the first point more than `tolerance` away from it, rather than simply the next point, and the
+/// strip is cut off `max_distance` from the last kept point. This stops long, nearly straight
+/// stretches from being collapsed into a single segment, and makes the result less sensitive to
+/// small wiggles just after each kept point.
+///
+/// This is also a linear-time, single-pass algorithm.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let points = points![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0), (4.0, 0.0)];
+///
+/// assert_eq!(simplify_opheim(&points, 0.5, 10.0), points![(0.0, 0.0), (4.0, 0.0)]);
+/// assert_eq!(
+///     simplify_opheim(&points, 0.5, 2.0),
+///     points![(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)]
+/// );
+/// ```
+pub fn simplify_opheim<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    tolerance: T,
+    max_distance: T,
+) -> Vec<Point<D, T>> {
+    kept_points(points, opheim_indices(points, tolerance, max_distance))
+}
+
+/// Same as [simplify_opheim], but returns the indices of the kept points.
+pub(crate) fn opheim_indices<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    tolerance: T,
+    max_distance: T,
+) -> Vec<usize> {
+    if points.len() <= 2 {
+        return (0..points.len()).collect();
+    }
+
+    let last = points.len() - 1;
+    let tolerance_sq = SqDist::new(tolerance * tolerance);
+    let max_distance_sq = SqDist::new(max_distance * max_distance);
+    let mut kept = vec![0];
+    let mut key = 0;
+
+    while key < last {
+        let mut direction = key + 1;
+        while direction < last && SqDist::between(&points[direction], &points[key]) <= tolerance_sq
+        {
+            direction += 1;
+        }
+
+        let mut next = direction;
+        while next < last {
+            let candidate = &points[next + 1];
+            if sq_line_dist(candidate, &points[key], &points[direction]) > tolerance_sq
+                || SqDist::between(candidate, &points[key]) > max_distance_sq
+            {
+                break;
+            }
+            next += 1;
+        }
+
+        kept.push(next);
+        key = next;
+    }
+
+    kept
+}
+
+/// Simplifies a polyline within a given tolerance using the Lang algorithm.
+///
+/// From the last kept point, a window of the next `look_ahead` points is searched. If every point
+/// in the window is within `tolerance` of the segment from the last kept point to the window's end,
+/// the end is kept and a new window starts there. Otherwise the window is shrunk by one point until
+/// they are.
+///
+/// Unlike [simplify_reumann_witkam] and [simplify_opheim], every dropped point is within
+/// `tolerance` of the simplified polyline, as with Douglas–Peucker. It looks at most `look_ahead`
+/// points ahead, so it can still simplify a polyline as it is being streamed in, and is linear in
+/// the number of points for a fixed `look_ahead`, though quadratic in `look_ahead` itself.
+///
+/// # Panics
+///
+/// If `look_ahead` is 0.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let points = points![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0), (4.0, 0.0)];
+///
+/// assert_eq!(simplify_lang(&points, 0.5, 8), points![(0.0, 0.0), (4.0, 0.0)]);
+/// assert_eq!(
+///     simplify_lang(&points, 0.5, 2),
+///     points![(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)]
+/// );
+/// ```
+pub fn simplify_lang<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    tolerance: T,
+    look_ahead: usize,
+) -> Vec<Point<D, T>> {
+    kept_points(points, lang_indices(points, tolerance, look_ahead))
+}
+
+/// Same as [simplify_lang], but returns the indices of the kept points.
+pub(crate) fn lang_indices<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    tolerance: T,
+    look_ahead: usize,
+) -> Vec<usize> {
+    assert!(look_ahead > 0, "look_ahead must be at least 1");
+    if points.len() <= 2 {
+        return (0..points.len()).collect();
+    }
+
+    let last = points.len() - 1;
+    let tolerance_sq = SqDist::new(tolerance * tolerance);
+    let mut kept = vec![0];
+    let mut key = 0;
+
+    while key < last {
+        let mut end = last.min(key + look_ahead);
+        while end > key + 1
+            && (key + 1..end)
+                .any(|i| sq_seg_dist(&points[i], &points[key], &points[end]) > tolerance_sq)
+        {
+            end -= 1;
+        }
+
+        kept.push(end);
+        key = end;
+    }
+
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        lang_indices, opheim_indices, reumann_witkam_indices, simplify_lang, simplify_opheim,
+        simplify_reumann_witkam,
+    };
+    use crate::{get_sq_seg_dist, points, sq_line_dist, Point};
+
+    /// A road running east, wobbling slightly, then curving north and back east.
+    fn road() -> [Point<2, f64>; 18] {
+        points![
+            (0.0, 0.0),
+            (1.0, 0.2),
+            (2.0, -0.1),
+            (3.0, 0.3),
+            (4.0, 0.0),
+            (5.0, 0.8),
+            (6.0, 1.9),
+            (7.0, 3.1),
+            (7.5, 4.0),
+            (7.8, 5.2),
+            (8.0, 6.1),
+            (8.1, 7.0),
+            (8.4, 8.2),
+            (9.3, 8.9),
+            (10.5, 9.2),
+            (11.6, 9.1),
+            (12.8, 9.4),
+            (14.0, 9.0)
+        ]
+    }
+
+    #[test]
+    fn short_polylines_are_unchanged() {
+        let points = [Point { vec: [0.0, 0.0] }, Point { vec: [1.0, 5.0] }];
+        for len in 0..=2 {
+            let points = &points[..len];
+            assert_eq!(simplify_reumann_witkam(points, 1.0), points);
+            assert_eq!(simplify_opheim(points, 1.0, 5.0), points);
+            assert_eq!(simplify_lang(points, 1.0, 4), points);
+        }
+    }
+
+    #[test]
+    fn reumann_witkam_stays_in_strips() {
+        let points = road();
+        let kept = reumann_witkam_indices(&points, 0.5);
+        assert_eq!(kept, [0, 3, 4, 6, 8, 12, 14, 17]);
+        for pair in kept.windows(2) {
+            for i in pair[0] + 1..pair[1] {
+                assert!(
+                    sq_line_dist(&points[i], &points[pair[0]], &points[pair[0] + 1]).value()
+                        <= 0.25
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn opheim_stays_in_strips_and_range() {
+        let points = road();
+        let kept = opheim_indices(&points, 0.5, 3.0);
+        assert_eq!(kept, [0, 2, 3, 4, 6, 8, 10, 12, 14, 16, 17]);
+        for pair in kept.windows(2) {
+            // Only the point that sets a strip's direction may be further than `max_distance`.
+            if pair[1] - pair[0] > 1 {
+                assert!(points[pair[1]].sq_dist(&points[pair[0]]) <= 9.0);
+            }
+        }
+
+        // Far enough, and the strips are only cut off by the points outside them.
+        assert_eq!(
+            opheim_indices(&points, 0.5, 10.0),
+            reumann_witkam_indices(&points, 0.5)
+        );
+    }
+
+    #[test]
+    fn lang_stays_within_tolerance() {
+        let points = road();
+        for look_ahead in [1, 4, 16] {
+            let kept = lang_indices(&points, 0.5, look_ahead);
+            for pair in kept.windows(2) {
+                assert!(pair[1] - pair[0] <= look_ahead);
+                for i in pair[0] + 1..pair[1] {
+                    assert!(
+                        get_sq_seg_dist(&points[i], &points[pair[0]], &points[pair[1]]) <= 0.25
+                    );
+                }
+            }
+        }
+        assert_eq!(lang_indices(&points, 0.5, 4), [0, 4, 8, 12, 16, 17]);
+        assert_eq!(simplify_lang(&points, 0.5, 1), points);
+    }
+}
@@ -1,5 +1,5 @@
-use num_traits::Num;
+use num_traits::{Num, NumCast};
 
 /// Required traits a number type must implement to be used with [simplify](crate::simplify).
-pub trait ExtendedNumOps: Num + Clone + Copy + PartialOrd {}
-impl<T> ExtendedNumOps for T where T: Num + Clone + Copy + PartialOrd {}
+pub trait ExtendedNumOps: Num + NumCast + Clone + Copy + PartialOrd {}
+impl<T> ExtendedNumOps for T where T: Num + NumCast + Clone + Copy + PartialOrd {}
@@ -0,0 +1,355 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{is_integer, wide_dot, wide_offset, widen, ExtendedNumOps, Point, SqDist};
+
+/// Squared synchronized Euclidean distance: the squared distance between `pt` and where an object
+/// moving at constant speed from `start` to `end` would have been at `time`.
+///
+/// For integer types, this is exact, as `|duration * (pt - start) - (end - start) * elapsed|²`
+/// over `duration²`, rather than truncating `elapsed / duration`, so it compares exactly against
+/// the distances of other points between the same `start` and `end`.
+fn sq_sync_dist<const D: usize, T: ExtendedNumOps, U: ExtendedNumOps>(
+    pt: &Point<D, T>,
+    time: U,
+    start: &Point<D, T>,
+    start_time: U,
+    end: &Point<D, T>,
+    end_time: U,
+) -> SqDist<T> {
+    if is_integer::<T>() {
+        let offset = wide_offset(pt, start);
+        let difference = wide_offset(end, start);
+        let elapsed = widen(time) - widen(start_time);
+        let duration = widen(end_time) - widen(start_time);
+        if duration == 0 {
+            return SqDist::Int {
+                whole: wide_dot(&offset, &offset),
+                fraction: 0,
+            };
+        }
+
+        let mut scaled = [0; D];
+        for (s, (&o, &d)) in scaled.iter_mut().zip(offset.iter().zip(difference.iter())) {
+            *s = duration * o - d * elapsed;
+        }
+        let sq_scaled = wide_dot(&scaled, &scaled);
+        let sq_duration = duration * duration;
+        return SqDist::Int {
+            whole: sq_scaled / sq_duration,
+            fraction: sq_scaled % sq_duration,
+        };
+    }
+
+    let mut expected = *start;
+    let duration = end_time - start_time;
+
+    if duration != U::zero() {
+        let t = to_coordinate::<T, U>(time - start_time) / to_coordinate(duration);
+        expected = expected + ((end - start) * t);
+    }
+
+    SqDist::Float((pt - expected).sq_dist_origin())
+}
+
+/// A length of time as a float coordinate.
+#[inline(always)]
+fn to_coordinate<T: ExtendedNumOps, U: ExtendedNumOps>(time: U) -> T {
+    num_traits::cast(time).expect("timestamps must fit in the coordinate type")
+}
+
+fn max_sq_sync_dist<const D: usize, T: ExtendedNumOps, U: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    timestamps: &[U],
+    first: usize,
+    last: usize,
+    tolerance: T,
+) -> (usize, SqDist<T>) {
+    let mut max_sq_dist = SqDist::new(tolerance);
+    let mut max_index = 0;
+
+    for i in first + 1..last {
+        let sq_dist = sq_sync_dist(
+            &points[i],
+            timestamps[i],
+            &points[first],
+            timestamps[first],
+            &points[last],
+            timestamps[last],
+        );
+        if sq_dist > max_sq_dist {
+            max_index = i;
+            max_sq_dist = sq_dist;
+        }
+    }
+
+    (max_index, max_sq_dist)
+}
+
+fn simplify_sync_dp_step<const D: usize, T: ExtendedNumOps, U: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    timestamps: &[U],
+    first: usize,
+    last: usize,
+    tolerance: T,
+    kept: &mut Vec<usize>,
+) {
+    let (max_index, max_sq_dist) = max_sq_sync_dist(points, timestamps, first, last, tolerance);
+
+    if max_sq_dist > SqDist::new(tolerance) {
+        if (max_index - first) > 1 {
+            simplify_sync_dp_step(points, timestamps, first, max_index, tolerance, kept);
+        }
+        kept.push(max_index);
+        if (last - max_index) > 1 {
+            simplify_sync_dp_step(points, timestamps, max_index, last, tolerance, kept);
+        }
+    }
+}
+
+fn check_timestamps<const D: usize, T: ExtendedNumOps, U>(
+    points: &[Point<D, T>],
+    timestamps: &[U],
+) {
+    assert_eq!(
+        points.len(),
+        timestamps.len(),
+        "there must be exactly one timestamp per point"
+    );
+}
+
+/// Simplifies a trajectory within a given tolerance using Douglas–Peucker with synchronized
+/// Euclidean distance, returning the indices of the kept points.
+///
+/// Instead of measuring how far a point is from the segment it would be dropped onto, this
+/// measures how far it is from where an object moving at constant speed along that segment would
+/// have been at the point's timestamp. A point that lies on the segment but was reached much
+/// earlier or later than that is kept, so the simplified trajectory still says where the object
+/// was at any given moment, not just which path it took.
+///
+/// `timestamps` should be non-decreasing, and can be of a different type to the coordinates, such
+/// as integer milliseconds for float coordinates, as long as they convert to it. Points between
+/// two kept points with the same timestamp are measured against the first of them.
+///
+/// For integer coordinates, where a point should have been is found exactly, rather than by
+/// rounding the fraction of the time taken, as long as the timestamps are integers too.
+///
+/// # Panics
+///
+/// If `points` and `timestamps` are not the same length.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let points = points![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (10.0, 0.0)];
+///
+/// // Constant speed, so only the ends are needed.
+/// assert_eq!(simplify_trajectory(&points, &[0.0, 1.0, 2.0, 10.0], 0.5), vec![0, 3]);
+///
+/// // A long stop at (2, 0), which a purely spatial simplification would lose.
+/// assert_eq!(simplify_trajectory(&points, &[0.0, 1.0, 2.0, 60.0], 0.5), vec![0, 2, 3]);
+///
+/// // The same, with timestamps in milliseconds.
+/// assert_eq!(
+///     simplify_trajectory(&points, &[0u64, 1000, 2000, 60_000], 0.5),
+///     vec![0, 2, 3]
+/// );
+/// ```
+pub fn simplify_trajectory<const D: usize, T: ExtendedNumOps, U: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    timestamps: &[U],
+    tolerance: T,
+) -> Vec<usize> {
+    check_timestamps(points, timestamps);
+    if points.len() <= 2 {
+        return (0..points.len()).collect();
+    }
+
+    let mut kept = vec![0];
+    simplify_sync_dp_step(
+        points,
+        timestamps,
+        0,
+        points.len() - 1,
+        tolerance * tolerance,
+        &mut kept,
+    );
+    kept.push(points.len() - 1);
+
+    kept
+}
+
+/// Simplifies a trajectory within a given tolerance using an opening window with synchronized
+/// Euclidean distance, returning the indices of the kept points.
+///
+/// Starting from the last kept point, the window is widened one point at a time until some point
+/// inside it is further than `tolerance` from where it would have been on the window's segment at
+/// its timestamp. The point before the one that widened the window too far is then kept, and
+/// starts the next window.
+///
+/// Unlike [simplify_trajectory], this only ever looks ahead of the last kept point, so it can
+/// simplify a trajectory as it is being recorded, but it generally keeps more points. It is
+/// `O(n²)` in the worst case. See [simplify_trajectory] for the distance measure, and the
+/// requirements on `timestamps`.
+///
+/// # Panics
+///
+/// If `points` and `timestamps` are not the same length.
+pub fn simplify_trajectory_opening_window<const D: usize, T: ExtendedNumOps, U: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    timestamps: &[U],
+    tolerance: T,
+) -> Vec<usize> {
+    check_timestamps(points, timestamps);
+    if points.len() <= 2 {
+        return (0..points.len()).collect();
+    }
+
+    let tolerance_sq = tolerance * tolerance;
+    let mut kept = vec![0];
+    let mut anchor = 0;
+    let mut end = 2;
+
+    while end < points.len() {
+        let (_, max_sq_dist) = max_sq_sync_dist(points, timestamps, anchor, end, tolerance_sq);
+        if max_sq_dist > SqDist::new(tolerance_sq) {
+            anchor = end - 1;
+            kept.push(anchor);
+        }
+        end += 1;
+    }
+    kept.push(points.len() - 1);
+
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{simplify_trajectory, simplify_trajectory_opening_window, sq_sync_dist};
+    use crate::Point;
+
+    fn track() -> (Vec<Point<2, f64>>, Vec<f64>) {
+        let mut time = 0.0;
+        (0..80)
+            .map(|i| {
+                let t = i as f64 * 0.25;
+                // Speeds up and slows down along a gently curving path.
+                time += 1.0 + (t * 0.7).sin().abs() * 4.0;
+                (
+                    Point {
+                        vec: [t * 3.0, (t * 0.4).sin() * 5.0],
+                    },
+                    time,
+                )
+            })
+            .unzip()
+    }
+
+    /// Every dropped point must be within tolerance of its kept neighbours' segment, in time.
+    fn assert_within_tolerance(points: &[Point<2, f64>], timestamps: &[f64], kept: &[usize]) {
+        assert_eq!(kept.first(), Some(&0));
+        assert_eq!(kept.last(), Some(&(points.len() - 1)));
+        for pair in kept.windows(2) {
+            let (first, last) = (pair[0], pair[1]);
+            for i in first + 1..last {
+                let sq_dist = sq_sync_dist(
+                    &points[i],
+                    timestamps[i],
+                    &points[first],
+                    timestamps[first],
+                    &points[last],
+                    timestamps[last],
+                )
+                .value();
+                assert!(sq_dist <= 1.0, "point {i} is {sq_dist} away");
+            }
+        }
+    }
+
+    #[test]
+    fn douglas_peucker_within_tolerance() {
+        let (points, timestamps) = track();
+        let kept = simplify_trajectory(&points, &timestamps, 1.0);
+        assert_within_tolerance(&points, &timestamps, &kept);
+        assert!(kept.len() < points.len());
+    }
+
+    #[test]
+    fn opening_window_within_tolerance() {
+        let (points, timestamps) = track();
+        let kept = simplify_trajectory_opening_window(&points, &timestamps, 1.0);
+        assert_within_tolerance(&points, &timestamps, &kept);
+        assert!(kept.len() < points.len());
+    }
+
+    #[test]
+    fn keeps_stops_on_straight_lines() {
+        let points: Vec<Point<2, f64>> = (0..10)
+            .map(|i| Point {
+                vec: [i as f64, 0.0],
+            })
+            .collect();
+        let mut timestamps: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        assert_eq!(simplify_trajectory(&points, &timestamps, 0.5), [0, 9]);
+        assert_eq!(
+            simplify_trajectory_opening_window(&points, &timestamps, 0.5),
+            [0, 9]
+        );
+
+        for time in &mut timestamps[5..] {
+            *time += 100.0;
+        }
+        assert_eq!(simplify_trajectory(&points, &timestamps, 0.5), [0, 4, 5, 9]);
+        assert_eq!(
+            simplify_trajectory_opening_window(&points, &timestamps, 0.5),
+            [0, 4, 5, 9]
+        );
+    }
+
+    #[test]
+    fn integer_coordinates() {
+        // Halfway through, at constant speed, which truncating 5 / 10 would put back at the start.
+        let points = [
+            Point { vec: [0i32, 0] },
+            Point { vec: [5, 0] },
+            Point { vec: [10, 0] },
+        ];
+        assert_eq!(simplify_trajectory(&points, &[0i64, 5, 10], 1), [0, 2]);
+        assert_eq!(
+            simplify_trajectory_opening_window(&points, &[0i64, 5, 10], 1),
+            [0, 2]
+        );
+
+        // At 2 / 3 of the time, (5, 0) should have been at (6⅔, 0), 1⅔ away.
+        assert_eq!(simplify_trajectory(&points, &[0u32, 2, 3], 2), [0, 2]);
+        assert_eq!(simplify_trajectory(&points, &[0u32, 2, 3], 1), [0, 1, 2]);
+
+        // Coordinates whose squared distances overflow an i32. Halfway along the diagonal, the
+        // object should have been at (0, 0), 3 away.
+        let points = [
+            Point {
+                vec: [-1_000_000_000, -1_000_000_000],
+            },
+            Point { vec: [0, 3] },
+            Point {
+                vec: [1_000_000_000, 1_000_000_000],
+            },
+        ];
+        let timestamps = [0u64, 1_000_000, 2_000_000];
+        assert_eq!(simplify_trajectory(&points, &timestamps, 2), [0, 1, 2]);
+        assert_eq!(simplify_trajectory(&points, &timestamps, 3), [0, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_timestamps() {
+        let points: Vec<Point<2, f64>> = (0..10)
+            .map(|i| Point {
+                vec: [i as f64, 0.0],
+            })
+            .collect();
+        simplify_trajectory(&points, &[0.0; 9], 1.0);
+    }
+}
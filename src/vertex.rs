@@ -0,0 +1,215 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{ExtendedNumOps, Point};
+
+/// Anything with `D` coordinates of type `T`, which can be simplified with
+/// [simplify_vertices](crate::simplify_vertices) without first being converted to a [Point].
+///
+/// This is implemented for [Point], `[T; D]`, and 2 and 3 element tuples. With the `geo-types`,
+/// `glam`, `mint` and `nalgebra` features, it is also implemented for their vector and point
+/// types. Implement it for your own vertex types to simplify them directly, and get back the kept
+/// vertices along with whatever else they carry.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Fix {
+///     lat: f64,
+///     lon: f64,
+///     timestamp: u64,
+/// }
+///
+/// impl Vertex<2, f64> for Fix {
+///     fn coords(&self) -> [f64; 2] {
+///         [self.lon, self.lat]
+///     }
+/// }
+///
+/// let track: Vec<Fix> = (0..5)
+///     .map(|i| Fix { lat: i as f64, lon: i as f64, timestamp: i * 60 })
+///     .collect();
+///
+/// let simplified = simplify_vertices(&track, 1.0, true);
+/// assert_eq!(simplified, vec![track[0].clone(), track[4].clone()]);
+/// ```
+pub trait Vertex<const D: usize, T: ExtendedNumOps> {
+    /// Returns the coordinates of this vertex.
+    fn coords(&self) -> [T; D];
+}
+
+impl<const D: usize, T: ExtendedNumOps> Vertex<D, T> for Point<D, T> {
+    #[inline(always)]
+    fn coords(&self) -> [T; D] {
+        self.vec
+    }
+}
+
+impl<const D: usize, T: ExtendedNumOps> Vertex<D, T> for [T; D] {
+    #[inline(always)]
+    fn coords(&self) -> [T; D] {
+        *self
+    }
+}
+
+impl<T: ExtendedNumOps> Vertex<2, T> for (T, T) {
+    #[inline(always)]
+    fn coords(&self) -> [T; 2] {
+        [self.0, self.1]
+    }
+}
+
+impl<T: ExtendedNumOps> Vertex<3, T> for (T, T, T) {
+    #[inline(always)]
+    fn coords(&self) -> [T; 3] {
+        [self.0, self.1, self.2]
+    }
+}
+
+/// Simplifies a polyline of any [Vertex] type within a given tolerance, returning clones of the
+/// kept vertices.
+///
+/// This keeps the same vertices as [simplify](crate::simplify) would for their coordinates. See
+/// [simplify](crate::simplify) for the meaning of `tolerance` and `high_quality`.
+#[cfg(feature = "alloc")]
+pub fn simplify_vertices<const D: usize, T: ExtendedNumOps, V: Vertex<D, T> + Clone>(
+    vertices: &[V],
+    tolerance: T,
+    high_quality: bool,
+) -> Vec<V> {
+    crate::source::simplify_indices(vertices, tolerance, high_quality)
+        .into_iter()
+        .map(|i| vertices[i].clone())
+        .collect()
+}
+
+/// The point half of every `(point, data)` item.
+#[cfg(feature = "alloc")]
+struct WithData<'a, const D: usize, T: ExtendedNumOps, A>(&'a [(Point<D, T>, A)]);
+
+#[cfg(feature = "alloc")]
+impl<'a, const D: usize, T: ExtendedNumOps, A> crate::source::PointSource<D, T>
+    for WithData<'a, D, T, A>
+{
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline(always)]
+    fn point(&self, index: usize) -> Point<D, T> {
+        self.0[index].0
+    }
+}
+
+/// Simplifies a polyline of `(point, data)` items on their points alone, returning clones of the
+/// kept items, data included.
+///
+/// This keeps the same points as [simplify](crate::simplify) would for the points on their own.
+/// See [simplify](crate::simplify) for the meaning of `tolerance` and `high_quality`.
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// let timestamps = [0, 10, 20, 30, 40];
+/// let items: Vec<_> = points![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (4.0, 4.0)]
+///     .into_iter()
+///     .zip(timestamps)
+///     .collect();
+///
+/// assert_eq!(
+///     simplify_with_data(&items, 1.0, true),
+///     vec![(point!(0.0, 0.0), 0), (point!(4.0, 4.0), 40)]
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn simplify_with_data<const D: usize, T: ExtendedNumOps, A: Clone>(
+    items: &[(Point<D, T>, A)],
+    tolerance: T,
+    high_quality: bool,
+) -> Vec<(Point<D, T>, A)> {
+    crate::source::simplify_indices(&WithData(items), tolerance, high_quality)
+        .into_iter()
+        .map(|i| items[i].clone())
+        .collect()
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{simplify_vertices, simplify_with_data, Vertex};
+    use crate::{simplify, Point};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Sample {
+        id: u32,
+        position: (f64, f64, f64),
+        speed: f32,
+    }
+
+    impl Vertex<3, f64> for Sample {
+        fn coords(&self) -> [f64; 3] {
+            self.position.coords()
+        }
+    }
+
+    fn samples() -> Vec<Sample> {
+        (0..60)
+            .map(|i| {
+                let t = i as f64 * 0.3;
+                Sample {
+                    id: i,
+                    position: (t, t.sin() * 3.0, t.cos() * 2.0),
+                    speed: i as f32,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keeps_same_vertices_as_simplify() {
+        let samples = samples();
+        let points: Vec<Point<3, f64>> =
+            samples.iter().map(|s| Point { vec: s.coords() }).collect();
+
+        for high_quality in [true, false] {
+            let kept = simplify_vertices(&samples, 0.5, high_quality);
+            let kept_points: Vec<_> = kept.iter().map(|s| Point { vec: s.coords() }).collect();
+            assert_eq!(kept_points, simplify(&points, 0.5, high_quality));
+            assert!(kept.windows(2).all(|w| w[0].id < w[1].id));
+            assert!(kept.iter().all(|s| s.speed == s.id as f32));
+        }
+    }
+
+    #[test]
+    fn arrays_and_tuples() {
+        let tuples: Vec<(f64, f64)> = (0..20).map(|i| (i as f64, (i % 3) as f64)).collect();
+        let arrays: Vec<[f64; 2]> = tuples.iter().map(|&(x, y)| [x, y]).collect();
+
+        let kept_tuples: Vec<[f64; 2]> = simplify_vertices(&tuples, 1.0, true)
+            .iter()
+            .map(Vertex::coords)
+            .collect();
+        assert_eq!(kept_tuples, simplify_vertices(&arrays, 1.0, true));
+    }
+
+    #[test]
+    fn data_follows_kept_points() {
+        let items: Vec<(Point<3, f64>, u32)> = samples()
+            .into_iter()
+            .map(|s| (Point { vec: s.coords() }, s.id))
+            .collect();
+        let points: Vec<_> = items.iter().map(|&(p, _)| p).collect();
+
+        for high_quality in [true, false] {
+            let kept = simplify_with_data(&items, 0.5, high_quality);
+            let expected: Vec<_> = simplify(&points, 0.5, high_quality);
+            assert_eq!(kept.iter().map(|&(p, _)| p).collect::<Vec<_>>(), expected);
+            assert!(kept.iter().all(|&(p, id)| p == points[id as usize]));
+        }
+    }
+}
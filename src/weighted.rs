@@ -0,0 +1,160 @@
+use alloc::vec::Vec;
+
+use crate::{
+    source::{simplify_indices, PointSource},
+    ExtendedNumOps, Point,
+};
+
+/// Every point multiplied component-wise by the weights.
+struct Weighted<'a, const D: usize, T: ExtendedNumOps> {
+    points: &'a [Point<D, T>],
+    weights: Point<D, T>,
+}
+
+impl<'a, const D: usize, T: ExtendedNumOps> PointSource<D, T> for Weighted<'a, D, T> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    #[inline(always)]
+    fn point(&self, index: usize) -> Point<D, T> {
+        self.points[index] * self.weights
+    }
+}
+
+/// Simplifies a polyline within a given tolerance, after scaling each axis by its own weight.
+///
+/// Distances are measured between points whose `i`th coordinate has been multiplied by
+/// `weights[i]`, in both the radial distance and Douglas–Peucker passes, so `tolerance` is in
+/// weighted units. This lets axes with different units or scales, like `(x, y, elevation, time)`
+/// or `(lon, lat)` away from the equator, be simplified together. For a separate tolerance per
+/// axis, use `1 / axis_tolerance` as that axis' weight, and a `tolerance` of 1. A weight of 0
+/// ignores that axis entirely.
+///
+/// The kept points are returned unscaled. With every weight set to 1, this keeps exactly the same
+/// points as [simplify](crate::simplify).
+///
+/// ## Example
+/// ```
+/// use simplify_polyline::*;
+///
+/// // (lon, lat) around 60°N, where a degree of longitude is half as long as a degree of latitude.
+/// let points = points![(10.0, 60.0), (10.5, 60.0), (11.0, 60.002), (11.5, 60.0), (12.0, 60.0)];
+///
+/// assert_eq!(
+///     simplify_weighted(&points, [0.5, 1.0], 0.01, true),
+///     points![(10.0, 60.0), (12.0, 60.0)]
+/// );
+/// ```
+pub fn simplify_weighted<const D: usize, T: ExtendedNumOps>(
+    points: &[Point<D, T>],
+    weights: [T; D],
+    tolerance: T,
+    high_quality: bool,
+) -> Vec<Point<D, T>> {
+    let weighted = Weighted {
+        points,
+        weights: Point { vec: weights },
+    };
+
+    simplify_indices(&weighted, tolerance, high_quality)
+        .into_iter()
+        .map(|i| points[i])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{points, simplify, simplify_weighted, Point};
+
+    /// An elevation profile, with distance along the route on one axis and height on the other.
+    fn profile() -> [Point<2, f64>; 11] {
+        points![
+            (0.0, 10.0),
+            (1.0, 10.2),
+            (2.0, 10.1),
+            (3.0, 10.6),
+            (4.0, 11.5),
+            (5.0, 11.4),
+            (6.0, 11.6),
+            (7.0, 10.9),
+            (8.0, 10.2),
+            (9.0, 10.3),
+            (10.0, 10.0)
+        ]
+    }
+
+    fn distances(points: &[Point<2, f64>]) -> Vec<f64> {
+        points.iter().map(|p| p.vec[0]).collect()
+    }
+
+    #[test]
+    fn unit_weights_match_simplify() {
+        let points = profile();
+        for high_quality in [true, false] {
+            assert_eq!(
+                simplify_weighted(&points, [1.0, 1.0], 0.2, high_quality),
+                simplify(&points, 0.2, high_quality)
+            );
+        }
+    }
+
+    #[test]
+    fn weights_scale_each_axis() {
+        let points = profile();
+        let stretched: Vec<Point<2, f64>> = points
+            .iter()
+            .map(|p| Point {
+                vec: [p.vec[0], p.vec[1] * 4.0],
+            })
+            .collect();
+
+        for high_quality in [true, false] {
+            let kept = simplify_weighted(&points, [1.0, 4.0], 0.5, high_quality);
+            let expected: Vec<Point<2, f64>> = simplify(&stretched, 0.5, high_quality)
+                .iter()
+                .map(|p| Point {
+                    vec: [p.vec[0], p.vec[1] / 4.0],
+                })
+                .collect();
+            assert_eq!(kept, expected);
+
+            // Exaggerating the height keeps the smaller bumps that it otherwise drops.
+            assert_eq!(
+                distances(&simplify(&points, 0.5, high_quality)),
+                [0.0, 6.0, 8.0, 10.0]
+            );
+            assert_eq!(
+                distances(&kept),
+                [0.0, 1.0, 2.0, 4.0, 5.0, 6.0, 8.0, 9.0, 10.0]
+            );
+        }
+    }
+
+    #[test]
+    fn zero_weight_ignores_axis() {
+        let points = profile();
+        assert_eq!(
+            simplify_weighted(&points, [1.0, 0.0], 0.5, true),
+            [points[0], points[points.len() - 1]]
+        );
+    }
+
+    #[test]
+    fn zero_weight_keeps_both_ends() {
+        let points = [
+            Point { vec: [0.0, 0.0] },
+            Point { vec: [0.0, 1.0] },
+            Point { vec: [0.0, 2.0] },
+        ];
+        for high_quality in [true, false] {
+            assert_eq!(
+                simplify_weighted(&points, [1.0, 0.0], 0.5, high_quality),
+                [points[0], points[2]]
+            );
+        }
+    }
+}